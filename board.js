@@ -0,0 +1,51 @@
+// How each move changes the position of the head
+export const directions = {
+  up: { x: 0, y: 1 },
+  down: { x: 0, y: -1 },
+  left: { x: -1, y: 0 },
+  right: { x: 1, y: 0 }
+};
+
+// Where pos ends up after moving one step in direction
+export function step(pos, direction) {
+  return { x: pos.x + directions[direction].x, y: pos.y + directions[direction].y };
+}
+
+// Wrapped games let snakes leave the board on one edge and come back on the
+// opposite one
+export function isWrapped(gameState) {
+  return gameState.game.ruleset.name === "wrapped";
+}
+
+// Brings a position that went off the edge back onto the board on wrapped
+// games, anywhere else it's left as is (and out of bounds)
+export function wrap(gameState, pos) {
+  if (!isWrapped(gameState)) return pos;
+  const boardWidth = gameState.board.width;
+  const boardHeight = gameState.board.height;
+  return {
+    x: (pos.x + boardWidth) % boardWidth,
+    y: (pos.y + boardHeight) % boardHeight
+  };
+}
+
+// The cells on the board next to cell, across the edges on wrapped games
+export function neighbours(gameState, cell) {
+  const boardWidth = gameState.board.width;
+  const boardHeight = gameState.board.height;
+  return Object.keys(directions)
+    .map((direction) => wrap(gameState, step(cell, direction)))
+    .filter((n) => n.x >= 0 && n.y >= 0 && n.x < boardWidth && n.y < boardHeight);
+}
+
+// Manhattan distance between a and b, taking the shorter way round the edges
+// on wrapped games
+export function distance(gameState, a, b) {
+  let x = Math.abs(a.x - b.x);
+  let y = Math.abs(a.y - b.y);
+  if (isWrapped(gameState)) {
+    x = Math.min(x, gameState.board.width - x);
+    y = Math.min(y, gameState.board.height - y);
+  }
+  return x + y;
+}
@@ -0,0 +1,16 @@
+// How far pos is from the middle of the board, from 0 in the center to 1 in a
+// corner. The pull towards the center fades as the board fills up, once it's
+// crowded the open space is wherever it is.
+export function centerDistance(gameState, pos) {
+  const boardWidth = gameState.board.width;
+  const boardHeight = gameState.board.height;
+  const cx = (boardWidth - 1) / 2;
+  const cy = (boardHeight - 1) / 2;
+  const d = (Math.abs(pos.x - cx) + Math.abs(pos.y - cy)) / Math.max(1, cx + cy);
+
+  let occupied = 0;
+  for (let snake of gameState.board.snakes) {
+    occupied += snake.body.length;
+  }
+  return d * Math.max(0, 1 - occupied / (boardWidth * boardHeight));
+}
@@ -1,26 +1,26 @@
+import { neck } from './snake.js';
+import { turnsUntilFree } from './turnsUntilFree.js';
+
 export function checkMoves(gameState, moves) {
   // Code that checks if any of the moves will result in colliding with another snake
-  const myHead = gameState.you.head;
-  gameState.board.snakes.forEach((snake) => {
-    for (let part of snake.body) {
-      if (part.y === myHead.y) {
-        if (part.x === myHead.x - 1) {
-          moves.left.safe = false;
-        }
-        else if (part.x === myHead.x + 1) {
-          moves.right.safe = false;
-        }
-      }
-      if (part.x === myHead.x) {
-        if (part.y === myHead.y - 1) {
-          moves.down.safe = false;
-        }
-        else if (part.y === myHead.y + 1) {
-          moves.up.safe = false;
-        }
-      }
+  // (or a maze wall, on maps that have them). Tails that move off this turn
+  // leave their cell free to follow right behind them.
+  const freeAt = turnsUntilFree(gameState);
+  for (let move in moves) {
+    const pos = moves[move].pos;
+    if ((freeAt.get(`${pos.x},${pos.y}`) ?? 0) > 1) {
+      moves[move].safe = false;
     }
-  });
+  }
+
+  // Never turn back into our own neck, even on a short snake where it's also
+  // the tail
+  const myNeck = neck(gameState.you);
+  for (let move in moves) {
+    if (myNeck && moves[move].pos.x === myNeck.x && moves[move].pos.y === myNeck.y) {
+      moves[move].safe = false;
+    }
+  }
 
   // Prevent your Battlesnake from moving out of bounds
   let boardWidth = gameState.board.width;
@@ -0,0 +1,31 @@
+import { isWrapped } from './board.js';
+import { distanceMap } from './distanceMap.js';
+import { floodFill } from './floodFill.js';
+import { predictedLength } from './headDanger.js';
+
+// Checks if moving to pos takes us into a corner that a snake at least as long
+// as us can seal behind us within two moves, leaving us less room than our own
+// body. Sealing a shorter snake into a corner is the same thing the other way
+// round, and is already taken as a cut-off.
+export function isCornerTrap(gameState, pos) {
+  if (isWrapped(gameState)) return false;
+
+  const me = gameState.you;
+  const boardWidth = gameState.board.width;
+  const boardHeight = gameState.board.height;
+  const nearX = pos.x <= 2 || pos.x >= boardWidth - 3;
+  const nearY = pos.y <= 2 || pos.y >= boardHeight - 3;
+  if (!nearX || !nearY) return false;
+
+  return gameState.board.snakes.some((snake) => {
+    if (snake.id === me.id || predictedLength(gameState, snake) < me.length) return false;
+
+    for (let [key, d] of distanceMap(gameState, snake.head)) {
+      if (d === 0 || d > 2) continue;
+      const [x, y] = key.split(",").map(Number);
+      if (x === pos.x && y === pos.y) continue;
+      if (floodFill(gameState, pos, [{ x: x, y: y }]) < me.length) return true;
+    }
+    return false;
+  });
+}
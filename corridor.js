@@ -0,0 +1,29 @@
+import { neighbours } from './board.js';
+import { floodFill } from './floodFill.js';
+import { blockedCells } from './occupancy.js';
+import { headThreats } from './headDanger.js';
+
+// A cell is in a corridor if, once our head is on it, there is at most one
+// free cell to carry on to
+export function isCorridor(gameState, pos) {
+  const walls = blockedCells(gameState);
+
+  const exits = neighbours(gameState, pos).filter((n) => !walls.has(`${n.x},${n.y}`));
+  return exits.length <= 1;
+}
+
+// Entering a corridor is only safe if there is room for our whole body on
+// the other side, otherwise we end up stuck behind our own tail
+export function isDeadEndCorridor(gameState, pos) {
+  return isCorridor(gameState, pos) && floodFill(gameState, pos) < gameState.you.length;
+}
+
+// Counts the ways out of pos on the move after next: free cells next to it
+// that no bigger snake can get to first. A single way out is a trap waiting
+// for an opponent to close it.
+export function escapeRoutes(gameState, pos) {
+  const walls = blockedCells(gameState);
+  return neighbours(gameState, pos).filter((n) =>
+    !walls.has(`${n.x},${n.y}`) && headThreats(gameState, n) === 0
+  ).length;
+}
@@ -0,0 +1,15 @@
+import { floodFill } from './floodFill.js';
+
+// Checks if moving our head to pos leaves an opponent with less room than
+// its own body, which means it will run out of space and die
+export function cutsOff(gameState, pos) {
+  const me = gameState.you;
+  return gameState.board.snakes.some((snake) => {
+    if (snake.id === me.id) return false;
+
+    // Ignore snakes that are already trapped, the move didn't do that
+    if (floodFill(gameState, snake.head) < snake.length) return false;
+
+    return floodFill(gameState, snake.head, [pos]) < snake.length;
+  });
+}
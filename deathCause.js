@@ -0,0 +1,40 @@
+import { distance } from './board.js';
+import { hazardCount } from './occupancy.js';
+
+// Works out from the final board how the game ended for us. Eliminated
+// snakes are off the board, but `you` still has where our head ended up,
+// which is usually enough to tell what happened. `previous` is the snapshot
+// of the turn before, if we have one, to spot snakes that died along with us.
+export function deathCause(gameState, previous) {
+  const me = gameState.you;
+  if (gameState.board.snakes.some((snake) => snake.id === me.id)) return "survived";
+
+  // Latency is reported as the game's timeout when the move didn't come back
+  // in time. The engine then moves us on in the same direction as last turn,
+  // so whatever we ran into is down to the timeout.
+  if (String(me.latency) === String(gameState.game.timeout)) return "timed-out";
+
+  const head = me.head;
+  const at = (part) => part.x === head.x && part.y === head.y;
+
+  if (me.health <= 0) return hazardCount(gameState, head) > 0 ? "hazard" : "starved";
+  if (head.x < 0 || head.y < 0 || head.x >= gameState.board.width || head.y >= gameState.board.height) return "wall";
+  if (me.body.slice(1).some(at)) return "self-collision";
+
+  for (let snake of gameState.board.snakes) {
+    if (at(snake.head)) return "head-to-head";
+    if (snake.body.some(at)) return "body-collision";
+  }
+
+  // A head-to-head with a snake our length takes both of us off the board.
+  // Look for one that was a move away from where our head ended up and
+  // didn't make it either.
+  if (previous !== undefined) {
+    for (let [id, snake] of previous.snakes) {
+      if (id === me.id || gameState.board.snakes.some((s) => s.id === id)) continue;
+      if (distance(gameState, snake.head, head) === 1) return "head-to-head";
+    }
+  }
+
+  return "unknown";
+}
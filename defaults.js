@@ -0,0 +1,29 @@
+// Fills in the parts of a request older engines and custom games sometimes
+// leave out, so the logic can rely on them being there
+export function withDefaults(gameState) {
+  const game = gameState.game ?? {};
+  const ruleset = game.ruleset ?? {};
+  const board = gameState.board ?? {};
+
+  return {
+    ...gameState,
+    game: {
+      ...game,
+      map: game.map ?? "standard",
+      source: game.source ?? "",
+      ruleset: {
+        ...ruleset,
+        name: ruleset.name ?? "standard",
+        // 14 is what the engine deals when a ruleset doesn't say
+        settings: { hazardDamagePerTurn: 14, ...ruleset.settings },
+      },
+    },
+    turn: gameState.turn ?? 0,
+    board: {
+      ...board,
+      food: board.food ?? [],
+      hazards: board.hazards ?? [],
+      snakes: board.snakes ?? [],
+    },
+  };
+}
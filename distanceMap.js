@@ -0,0 +1,28 @@
+import { neighbours } from './board.js';
+import { turnsUntilFree } from './turnsUntilFree.js';
+
+// Number of moves needed to reach every cell from start, going around walls
+// and any cells in `blocked`. Snake bodies only get in the way while they're
+// still there: a cell can be stepped on once the segment covering it has moved
+// off by the time we arrive. Cells that can't be reached are missing from the
+// map.
+export function distanceMap(gameState, start, blocked = []) {
+  const freeAt = turnsUntilFree(gameState);
+  const walls = new Set(blocked.map((c) => `${c.x},${c.y}`));
+
+  const distances = new Map([[`${start.x},${start.y}`, 0]]);
+  const queue = [start];
+  while (queue.length > 0) {
+    const cell = queue.shift();
+    const d = distances.get(`${cell.x},${cell.y}`);
+    for (let n of neighbours(gameState, cell)) {
+      const key = `${n.x},${n.y}`;
+      if (distances.has(key) || walls.has(key)) continue;
+      if ((freeAt.get(key) ?? 0) > d + 1) continue;
+      distances.set(key, d + 1);
+      queue.push(n);
+    }
+  }
+
+  return distances;
+}
@@ -0,0 +1,30 @@
+import { distanceMap } from './distanceMap.js';
+import { escapeRoutes, isDeadEndCorridor } from './corridor.js';
+import { headThreats } from './headDanger.js';
+import { renderBoard } from './render.js';
+
+// Logs the board and what every direction looked like this turn when
+// EXPLAIN_MOVES is set, to make sense of a bad move when going through the
+// logs of a loss
+export function explainMoves(gameState, possibleMoves) {
+  if (!process.env.EXPLAIN_MOVES) return;
+
+  console.log(renderBoard(gameState));
+
+  for (let move in possibleMoves) {
+    const pos = possibleMoves[move].pos;
+    const terms = [`safe: ${possibleMoves[move].safe}`];
+    if (possibleMoves[move].safe) {
+      const distances = distanceMap(gameState, pos);
+      const food = gameState.board.food.map((f) => distances.get(`${f.x},${f.y}`) ?? Infinity);
+      terms.push(
+        `room: ${possibleMoves[move].room ?? "-"}`,
+        `head threats: ${headThreats(gameState, pos)}`,
+        `dead end: ${isDeadEndCorridor(gameState, pos)}`,
+        `exits: ${escapeRoutes(gameState, pos)}`,
+        `food: ${food.length > 0 ? Math.min(...food) : "-"}`,
+      );
+    }
+    console.log(`  ${move}: ${terms.join(", ")}`);
+  }
+}
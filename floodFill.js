@@ -0,0 +1,31 @@
+import { neighbours } from './board.js';
+import { blockedCells } from './occupancy.js';
+
+// Counts the cells reachable from start without leaving the board or crossing
+// a snake body. The start cell itself is not counted, and any cells in
+// `blocked` are treated as walls on top of the snakes.
+export function floodFill(gameState, start, blocked = []) {
+  return floodRegion(gameState, start, blocked).size - 1;
+}
+
+// The "x,y" keys of the cells floodFill counts, plus start itself
+export function floodRegion(gameState, start, blocked = []) {
+  const walls = blockedCells(gameState);
+  for (let c of blocked) {
+    walls.add(`${c.x},${c.y}`);
+  }
+
+  const seen = new Set([`${start.x},${start.y}`]);
+  const queue = [start];
+  while (queue.length > 0) {
+    const cell = queue.shift();
+    for (let n of neighbours(gameState, cell)) {
+      const key = `${n.x},${n.y}`;
+      if (seen.has(key) || walls.has(key)) continue;
+      seen.add(key);
+      queue.push(n);
+    }
+  }
+
+  return seen;
+}
@@ -0,0 +1,30 @@
+// Groups the food on the board into clusters, two pieces of food belong to the
+// same cluster if they are at most `radius` moves apart
+export function foodClusters(food, radius = 2) {
+  const clusters = [];
+  for (let f of food) {
+    const near = clusters.filter((cluster) =>
+      cluster.some((c) => Math.abs(c.x - f.x) + Math.abs(c.y - f.y) <= radius)
+    );
+
+    // Merge every cluster this food touches into one
+    const merged = [f];
+    for (let cluster of near) {
+      merged.push(...cluster);
+      clusters.splice(clusters.indexOf(cluster), 1);
+    }
+    clusters.push(merged);
+  }
+  return clusters;
+}
+
+// Maps every piece of food to the size of the cluster it belongs to
+export function clusterSizes(food, radius = 2) {
+  const sizes = new Map();
+  for (let cluster of foodClusters(food, radius)) {
+    for (let f of cluster) {
+      sizes.set(f, cluster.length);
+    }
+  }
+  return sizes;
+}
@@ -0,0 +1,8 @@
+import { ownsCell } from './territory.js';
+
+// Filters out food an opponent will get to first. An opponent wins the race
+// if its path is shorter, or just as long and it's at least as long as us
+// (so we'd lose the head-to-head on the food).
+export function winnableFood(gameState) {
+  return gameState.board.food.filter((f) => ownsCell(gameState, f));
+}
@@ -0,0 +1,36 @@
+import { distance, neighbours } from './board.js';
+import { predictedLength } from './headDanger.js';
+import { gameMemory } from './memory.js';
+import { turnsUntilFree } from './turnsUntilFree.js';
+
+// An opponent is a greedy food seeker once we've watched it for this many
+// turns with food on the board, and it went straight for the nearest food on
+// nearly all of them
+const minTurns = 5;
+const share = 0.9;
+
+// Greedy opponents shorter than us, with the food they're going for and the
+// cell they'll step onto next to get there (if there's only one way to get
+// closer). We win a head-to-head on that cell, and can take the food first.
+export function greedySnakes(gameState) {
+  const me = gameState.you;
+  const memory = gameMemory(gameState);
+  const freeAt = turnsUntilFree(gameState);
+  const food = gameState.board.food;
+  if (food.length === 0) return [];
+
+  const greedy = [];
+  for (let snake of gameState.board.snakes) {
+    if (snake.id === me.id || predictedLength(gameState, snake) >= me.length) continue;
+    const counts = memory.foodChasing.get(snake.id);
+    if (counts === undefined || counts.turns < minTurns || counts.chased < counts.turns * share) continue;
+
+    const nearest = Math.min(...food.map((f) => distance(gameState, snake.head, f)));
+    const target = food.find((f) => distance(gameState, snake.head, f) === nearest);
+    const closer = neighbours(gameState, snake.head).filter((n) =>
+      (freeAt.get(`${n.x},${n.y}`) ?? 0) <= 1 && distance(gameState, n, target) < nearest
+    );
+    greedy.push({ snake: snake, food: target, next: closer.length === 1 ? closer[0] : undefined });
+  }
+  return greedy;
+}
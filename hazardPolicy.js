@@ -0,0 +1,89 @@
+import { neighbours } from './board.js';
+import { distanceMap } from './distanceMap.js';
+import { hazardPressure } from './hazardTrend.js';
+import { healthAfter, healthMap } from './healthProjection.js';
+import { hazardCount, isFood } from './occupancy.js';
+
+// A hazard shortcut has to save at least this many moves to the nearest food.
+// Once the hazards are closing in fast, food on the clear side won't stay
+// clear for long and the safe space left isn't worth much time holding on to,
+// so any saving will do.
+const shortcut = 3;
+const closingShortcut = 1;
+
+// Share of the clear cells lost to hazards in the last few turns that counts
+// as closing in fast
+const closingPressure = 0.25;
+
+// HAZARD_HEALTH_THRESHOLD sets the health we need left once we're through the
+// hazards for stepping into them to be worth considering
+export function hazardThreshold() {
+  const threshold = parseInt(process.env.HAZARD_HEALTH_THRESHOLD);
+  return isNaN(threshold) ? 40 : threshold;
+}
+
+// Drops moves into hazards unless they get us to food meaningfully faster than
+// going around them, and we keep enough health crossing them on the way
+export function avoidHazards(gameState, possibleMoves, safeMoves) {
+  const clearMoves = safeMoves.filter(move => hazardCount(gameState, possibleMoves[move].pos) === 0);
+  if (clearMoves.length === 0) return safeMoves;
+
+  const bestClear = Math.min(...clearMoves.map(move => clearFoodDistance(gameState, possibleMoves[move].pos)));
+  const saving = hazardPressure(gameState) >= closingPressure ? closingShortcut : shortcut;
+  return safeMoves.filter((move) => {
+    const pos = possibleMoves[move].pos;
+    if (hazardCount(gameState, pos) === 0) return true;
+
+    // With no food to get to, there's nothing for the damage to buy
+    const crossing = hazardCrossing(gameState, pos);
+    if (crossing === undefined) return false;
+    if (crossing.health <= hazardThreshold()) return false;
+    return crossing.d + saving <= bestClear;
+  });
+}
+
+// Follows the path from pos to the nearest food we can get to alive, and
+// returns how many moves away that food is along with the health we have left
+// where the hazards pos leads into end. Undefined if there's no food to get to.
+function hazardCrossing(gameState, pos) {
+  const cells = healthMap(gameState, pos, healthAfter(gameState, pos, gameState.you.health));
+  let target;
+  for (let f of gameState.board.food) {
+    const cell = cells.get(`${f.x},${f.y}`);
+    if (cell !== undefined && (target === undefined || cell.d < target.d)) target = { ...f, ...cell };
+  }
+  if (target === undefined) return undefined;
+
+  // Walk back to pos, through whichever cell on each step left us healthiest
+  const path = [target];
+  let cell = target;
+  while (cell.d > 0) {
+    const eats = isFood(gameState, cell);
+    let previous;
+    for (let n of neighbours(gameState, cell)) {
+      const seen = cells.get(`${n.x},${n.y}`);
+      if (seen === undefined || seen.d !== cell.d - 1) continue;
+      if (!eats && healthAfter(gameState, cell, seen.health) !== cell.health) continue;
+      if (previous === undefined || seen.health > previous.health) previous = { ...n, ...seen };
+    }
+    cell = previous;
+    path.unshift(cell);
+  }
+
+  let end = path[0];
+  for (let step of path) {
+    if (hazardCount(gameState, step) === 0) break;
+    end = step;
+  }
+  return { d: target.d, health: end.health };
+}
+
+// Moves to the nearest food from pos without touching a hazard
+function clearFoodDistance(gameState, pos) {
+  const distances = distanceMap(gameState, pos, gameState.board.hazards);
+  let nearest = Infinity;
+  for (let f of gameState.board.food) {
+    nearest = Math.min(nearest, distances.get(`${f.x},${f.y}`) ?? Infinity);
+  }
+  return nearest;
+}
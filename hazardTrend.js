@@ -0,0 +1,18 @@
+import { gameMemory } from './memory.js';
+import { occupancy } from './occupancy.js';
+
+// How many turns back we look to see how fast the hazards are spreading,
+// royale shrinks the board every 25 turns by default
+const window = 25;
+
+// Share of the cells still clear of hazards that were swallowed by them over
+// the last few turns. It grows as the hazards close in: the same row of new
+// hazards is a bigger part of a smaller safe area.
+export function hazardPressure(gameState) {
+  const memory = gameMemory(gameState);
+  const recent = memory.hazardSpread
+    .filter((spread) => spread.turn > gameState.turn - window)
+    .reduce((cells, spread) => cells + spread.cells, 0);
+  const clear = gameState.board.width * gameState.board.height - occupancy(gameState).hazards.size;
+  return clear > 0 ? recent / clear : 1;
+}
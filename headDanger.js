@@ -0,0 +1,38 @@
+import { distance } from './board.js';
+import { riskTolerance } from './risk.js';
+import { willGrow } from './snake.js';
+
+// Scores how dangerous the cell at pos is from opponents that could move their
+// head onto it next turn. A longer snake counts fully, one the same length only
+// as much as we're unwilling to risk trading heads with it.
+export function headThreats(gameState, pos) {
+  const me = gameState.you;
+  const risk = riskTolerance();
+  let threat = 0;
+  for (let snake of gameState.board.snakes) {
+    if (snake.id === me.id) continue;
+    if (distance(gameState, snake.head, pos) !== 1) continue;
+
+    const length = predictedLength(gameState, snake);
+    if (length > me.length) threat += 1;
+    else if (length === me.length) threat += 1 - risk;
+  }
+  return threat;
+}
+
+// Marks cells two or more bigger snakes can reach as unsafe, losing the
+// head-to-head is almost certain when more than one of them is going for it
+export function checkHeadDanger(gameState, moves) {
+  for (let move in moves) {
+    if (headThreats(gameState, moves[move].pos) >= 2) {
+      moves[move].safe = false;
+    }
+  }
+  return moves;
+}
+
+// An opponent with its head next to food will most likely eat it this turn,
+// so judge head-to-heads on the length it will have after growing
+export function predictedLength(gameState, snake) {
+  return willGrow(gameState, snake) ? snake.length + 1 : snake.length;
+}
@@ -0,0 +1,51 @@
+import { neighbours } from './board.js';
+import { hazardCount, isFood } from './occupancy.js';
+import { riskTolerance } from './risk.js';
+import { turnsUntilFree } from './turnsUntilFree.js';
+
+// Health left after spending a turn on cell: food fills us back up, otherwise
+// we lose one point plus the hazard damage for every hazard on the cell (some
+// maps stack them)
+export function healthAfter(gameState, cell, health) {
+  if (isFood(gameState, cell)) return 100;
+
+  const damage = 1 + hazardCount(gameState, cell) * gameState.game.ruleset.settings.hazardDamagePerTurn;
+  return health - damage;
+}
+
+// Walks the shortest paths from start like distanceMap (bodies block cells
+// until they move off them), projecting our health turn by turn along the
+// way. Cells we'd die before reaching are left out, and where two paths are
+// just as short we keep the one that hurts less. Unless we're willing to take
+// risks, a hazard is only entered with at least another hazard turn's worth of
+// health to spare.
+export function healthMap(gameState, start, health) {
+  const freeAt = turnsUntilFree(gameState);
+
+  const damage = gameState.game.ruleset.settings.hazardDamagePerTurn;
+  const margin = Math.round((1 - riskTolerance()) * damage);
+
+  const cells = new Map([[`${start.x},${start.y}`, { d: 0, health: health }]]);
+  const queue = [start];
+  while (queue.length > 0) {
+    const cell = queue.shift();
+    const here = cells.get(`${cell.x},${cell.y}`);
+    for (let n of neighbours(gameState, cell)) {
+      const key = `${n.x},${n.y}`;
+      if ((freeAt.get(key) ?? 0) > here.d + 1) continue;
+
+      const left = healthAfter(gameState, n, here.health);
+      if (left <= (hazardCount(gameState, n) > 0 ? margin : 0)) continue;
+
+      const seen = cells.get(key);
+      if (seen === undefined) {
+        cells.set(key, { d: here.d + 1, health: left });
+        queue.push(n);
+      } else if (seen.d === here.d + 1 && seen.health < left) {
+        seen.health = left;
+      }
+    }
+  }
+
+  return cells;
+}
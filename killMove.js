@@ -0,0 +1,18 @@
+import { neighbours } from './board.js';
+import { predictedLength } from './headDanger.js';
+import { turnsUntilFree } from './turnsUntilFree.js';
+
+// A kill move takes away the last reply a shorter opponent had: pos is the
+// only cell next to its head it can still move onto, and there it loses the
+// head-to-head with us. Tails that move off this turn leave their cell open.
+export function isKillMove(gameState, pos) {
+  const me = gameState.you;
+  const freeAt = turnsUntilFree(gameState);
+
+  return gameState.board.snakes.some((snake) => {
+    if (snake.id === me.id || predictedLength(gameState, snake) >= me.length) return false;
+
+    const replies = neighbours(gameState, snake.head).filter((n) => (freeAt.get(`${n.x},${n.y}`) ?? 0) <= 1);
+    return replies.length === 1 && replies[0].x === pos.x && replies[0].y === pos.y;
+  });
+}
@@ -0,0 +1,36 @@
+import { blockedCells } from './occupancy.js';
+import { headThreats } from './headDanger.js';
+import { justAte, neck, tail, willGrow } from './snake.js';
+
+// When every move looks fatal, rank them by how likely we are to get away
+// with it: an open cell bigger snakes might also go for beats a tail that
+// might move out of the way, which beats a body we'll surely hit, which
+// beats the edge of the board
+export function leastBadMove(gameState, possibleMoves) {
+  const boardWidth = gameState.board.width;
+  const boardHeight = gameState.board.height;
+  const walls = blockedCells(gameState);
+  const myNeck = neck(gameState.you);
+
+  const score = (pos) => {
+    if (pos.x < 0 || pos.y < 0 || pos.x >= boardWidth || pos.y >= boardHeight) return 0;
+    if (myNeck && pos.x === myNeck.x && pos.y === myNeck.y) return 0;
+    if (!walls.has(`${pos.x},${pos.y}`)) return headThreats(gameState, pos) >= 2 ? 3 : 4;
+
+    // A tail moves on next turn unless its snake is about to grow
+    const tailOf = gameState.board.snakes.find((snake) => tail(snake).x === pos.x && tail(snake).y === pos.y);
+    if (tailOf && !willGrow(gameState, tailOf) && !justAte(tailOf)) return 2;
+    return 1;
+  };
+
+  let best = "up";
+  let bestScore = -1;
+  for (let move in possibleMoves) {
+    const s = score(possibleMoves[move].pos);
+    if (s > bestScore) {
+      best = move;
+      bestScore = s;
+    }
+  }
+  return best;
+}
@@ -0,0 +1,332 @@
+// Welcome to
+// __________         __    __  .__                               __
+// \______   \_____ _/  |__/  |_|  |   ____   ______ ____ _____  |  | __ ____
+//  |    |  _/\__  \\   __\   __\  | _/ __ \ /  ___//    \\__  \ |  |/ // __ \
+//  |    |   \ / __ \|  |  |  | |  |_\  ___/ \___ \|   |  \/ __ \|    <\  ___/
+//  |________/(______/__|  |__| |____/\_____>______>___|__(______/__|__\\_____>
+//
+// This file can be a nice home for your Battlesnake logic and helper functions.
+//
+// To get you started we've included code to prevent your Battlesnake from moving backwards.
+// For more info see docs.battlesnake.com
+
+import { createHash } from 'node:crypto';
+
+import { directions, step, wrap } from './board.js';
+import { centerDistance } from './center.js';
+import { checkMoves } from './checkMoves.js';
+import { isCornerTrap } from './cornerTrap.js';
+import { escapeRoutes, isDeadEndCorridor } from './corridor.js';
+import { cutsOff } from './cutOff.js';
+import { deathCause } from './deathCause.js';
+import { explainMoves } from './explain.js';
+import { floodFill } from './floodFill.js';
+import { avoidHazards, hazardThreshold } from './hazardPolicy.js';
+import { checkHeadDanger, headThreats } from './headDanger.js';
+import { healthAfter, healthMap } from './healthProjection.js';
+import { clusterSizes } from './foodClusters.js';
+import { greedySnakes } from './greedy.js';
+import { winnableFood } from './foodRace.js';
+import { isKillMove } from './killMove.js';
+import { leastBadMove } from './leastBad.js';
+import { activeGames, forgetGame, gameMemory, recordTurn } from './memory.js';
+import { percentiles, recordGameEnd, recordGameStart, renderMetrics } from './metrics.js';
+import { moveTemperature, pickMove } from './mixedStrategy.js';
+import { pinsToWall } from './pin.js';
+import { regionAfterMove } from './reachable.js';
+import { riskTolerance } from './risk.js';
+import { shadowMoves } from './shadow.js';
+import { shout } from './shout.js';
+import { neck } from './snake.js';
+import { squadView } from './squad.js';
+import { shouldStall, stallMove } from './stall.js';
+import { survivalDepth, survivalHorizon } from './survival.js';
+
+// Above this much health we can afford to go after other snakes, or a bit
+// further for food, instead of heading straight for the nearest meal
+const healthToSpare = 30;
+
+// info is called when you create your Battlesnake on play.battlesnake.com
+// and controls your Battlesnake's appearance
+// TIP: If you open your Battlesnake URL in a browser you should see this data
+export function info() {
+  console.log("INFO");
+
+  return {
+    apiversion: "1",
+    author: "FusionStreak",
+    color: "#ff3d00",
+    head: "smart-caterpillar",
+    tail: "skinny",
+  };
+}
+
+// start is called when your Battlesnake begins a game
+export function start(gameState) {
+  console.log(`GAME START: ${gameState.game.id}`);
+  gameMemory(gameState);
+  recordGameStart();
+}
+
+// end is called when your Battlesnake finishes a game
+// Everything worth knowing about the game goes into a single JSON log line
+export function end(gameState) {
+  const previous = gameMemory(gameState).previous;
+  const memory = recordTurn(gameState);
+  const summary = {
+    game: gameState.game.id,
+    result: result(gameState),
+    placement: placement(gameState, previous),
+    cause: deathCause(gameState, previous),
+    turns: gameState.turn,
+    length: gameState.you.length,
+    peakLength: memory.peakLength,
+    foodEaten: memory.foodEaten,
+    eliminated: memory.eliminated,
+    snakesAtStart: memory.snakesAtStart,
+    health: gameState.you.health,
+    snakesLeft: gameState.board.snakes.length,
+    ruleset: gameState.game.ruleset.name,
+    map: gameState.game.map,
+    board: `${gameState.board.width}x${gameState.board.height}`,
+    config: config(),
+    configHash: configHash(),
+    moveTime: percentiles(memory.moveTimes),
+    memoryResets: memory.memoryResets,
+  };
+  console.log(`GAME OVER: ${JSON.stringify(summary)}\n`);
+  recordGameEnd(summary);
+  forgetGame(gameState);
+}
+
+// metrics is called when Prometheus scrapes /metrics
+export function metrics() {
+  return renderMetrics(activeGames());
+}
+
+// Works out how the game went for us from the final board, only the snakes
+// still alive are on it
+function result(gameState) {
+  if (gameState.board.snakes.some((snake) => snake.id === gameState.you.id)) return "win";
+  if (gameState.board.snakes.length === 0) return "draw";
+  return "loss";
+}
+
+// Where we finished, 1 for first. Once we're out we stop getting moves, so
+// the last turn we played tells us how many snakes were still in it; we count
+// all of them as having outlasted us. `previous` is that turn's snapshot.
+function placement(gameState, previous) {
+  if (result(gameState) !== "loss") return 1;
+  if (previous === undefined) return gameState.board.snakes.length + 1;
+  return previous.snakes.size;
+}
+
+// The settings that change how we play, as they were read this game
+function config() {
+  return {
+    shoutMode: process.env.SHOUT_MODE || "silent",
+    riskTolerance: riskTolerance(),
+    hazardThreshold: hazardThreshold(),
+    moveTemperature: moveTemperature(),
+  };
+}
+
+// A short hash of the config, so games can be grouped by how we were set up
+// without comparing every field
+function configHash() {
+  return createHash("sha256").update(JSON.stringify(config())).digest("hex").slice(0, 8);
+}
+
+// move is called on every turn and returns your next move
+// Valid moves are "up", "down", "left", or "right"
+// See https://docs.battlesnake.com/api/example-move for available data
+export function move(gameState) {
+  gameState = squadView(gameState);
+
+  // Catch up on what happened since last turn before anything looks at it
+  recordTurn(gameState);
+
+  // The possible moves the snake can make
+  const myHead = gameState.you.head;
+  let possibleMoves = {};
+  for (let direction in directions) {
+    possibleMoves[direction] = { safe: true, pos: wrap(gameState, step(myHead, direction)) };
+  }
+
+  possibleMoves = checkMoves(gameState, possibleMoves);
+  possibleMoves = checkHeadDanger(gameState, possibleMoves);
+
+  // Filter invalid moves
+  let safeMoves = Object.keys(possibleMoves).filter(key => possibleMoves[key].safe);
+
+  // Check if any moves left
+  if (safeMoves.length == 0) {
+    return respond(gameState, possibleMoves, leastBadMove(gameState, possibleMoves), "no safe moves, least bad");
+  }
+
+  // Prefer moves into a region we can survive in: one with room for our whole
+  // body, or with our tail in it to follow. If no region is survivable, play
+  // out each move and take the one we last longest on.
+  for (let move of safeMoves) {
+    const region = regionAfterMove(gameState, possibleMoves[move].pos);
+    possibleMoves[move].room = region.room;
+    possibleMoves[move].hasTail = region.hasTail;
+  }
+  const roomyMoves = safeMoves.filter(move => possibleMoves[move].room >= gameState.you.length || possibleMoves[move].hasTail);
+  if (roomyMoves.length > 0) {
+    safeMoves = roomyMoves;
+  } else {
+    for (let move of safeMoves) {
+      possibleMoves[move].survival = survivalDepth(gameState, possibleMoves[move].pos);
+    }
+    const longest = Math.max(...safeMoves.map(move => possibleMoves[move].survival));
+    safeMoves = safeMoves.filter(move => possibleMoves[move].survival === longest);
+    if (longest < survivalHorizon) {
+      return respond(gameState, possibleMoves, safeMoves[0], `doomed in ${longest}`);
+    }
+  }
+
+  // Among those, stay as far out of reach of bigger snakes' heads as we can
+  const leastThreat = Math.min(...safeMoves.map(move => headThreats(gameState, possibleMoves[move].pos)));
+  safeMoves = safeMoves.filter(move => headThreats(gameState, possibleMoves[move].pos) === leastThreat);
+
+  // Avoid narrow corridors that don't leave room for our body, unless there's nothing else
+  const openMoves = safeMoves.filter(move => !isDeadEndCorridor(gameState, possibleMoves[move].pos));
+  if (openMoves.length > 0) safeMoves = openMoves;
+
+  // Only step into hazards when it's a worthwhile shortcut we can afford
+  safeMoves = avoidHazards(gameState, possibleMoves, safeMoves);
+
+  // If the board has split, stay out of regions much smaller than the biggest
+  // one on offer
+  if (roomyMoves.length > 0) {
+    const biggest = Math.max(...safeMoves.map(move => possibleMoves[move].room));
+    safeMoves = safeMoves.filter(move => possibleMoves[move].room * 2 >= biggest);
+  }
+
+  // If only one safe move, return that move
+  if (safeMoves.length == 1) {
+    return respond(gameState, possibleMoves, safeMoves[0], "only safe move");
+  }
+
+  // Alone on the board we just need to survive, loop around after our tail
+  if (shouldStall(gameState)) {
+    return respond(gameState, possibleMoves, stallMove(gameState, possibleMoves, safeMoves), "stalling");
+  }
+
+  // Finish off a shorter snake that has no way out
+  const killMoves = safeMoves.filter(move => isKillMove(gameState, possibleMoves[move].pos));
+  if (killMoves.length > 0) {
+    return respond(gameState, possibleMoves, killMoves[0], "kill");
+  }
+
+  // A greedy snake shorter than us steps where we expect it to on its way to
+  // food, meet it there head-on
+  const greedy = greedySnakes(gameState);
+  const headOnMoves = safeMoves.filter((move) => {
+    const pos = possibleMoves[move].pos;
+    return greedy.some((g) => g.next !== undefined && g.next.x === pos.x && g.next.y === pos.y);
+  });
+  if (headOnMoves.length > 0) {
+    return respond(gameState, possibleMoves, headOnMoves[0], "head-on");
+  }
+
+  // With health to spare, go for the kill if a move walls an opponent into
+  // less room than its length, as long as we keep enough room for ourselves
+  if (gameState.you.health > healthToSpare) {
+    const cutOffMoves = safeMoves.filter((move) => {
+      const pos = possibleMoves[move].pos;
+      return cutsOff(gameState, pos) && floodFill(gameState, pos) >= gameState.you.length;
+    });
+    if (cutOffMoves.length > 0) {
+      return respond(gameState, possibleMoves, cutOffMoves[0], "cut-off");
+    }
+  }
+
+  // With health to spare, keep a shorter snake pinned against the wall
+  if (gameState.you.health > healthToSpare) {
+    const pinMoves = safeMoves.filter(move => pinsToWall(gameState, possibleMoves[move].pos));
+    if (pinMoves.length > 0) {
+      return respond(gameState, possibleMoves, pinMoves[0], "pinning");
+    }
+  }
+
+  // With health to spare, stay diagonal to the head of a snake a little
+  // shorter than us, taking away its room without risking a trade
+  if (gameState.you.health > healthToSpare) {
+    const shadowingMoves = shadowMoves(gameState, possibleMoves, safeMoves);
+    if (shadowingMoves.length > 0) {
+      return respond(gameState, possibleMoves, shadowingMoves[0], "shadowing");
+    }
+  }
+
+  // Stay out of corners a bigger snake can close behind us
+  const openCornerMoves = safeMoves.filter(move => !isCornerTrap(gameState, possibleMoves[move].pos));
+  if (openCornerMoves.length > 0) safeMoves = openCornerMoves;
+
+  // Keep more than one way out if we can
+  const escapeMoves = safeMoves.filter(move => escapeRoutes(gameState, possibleMoves[move].pos) > 1);
+  if (escapeMoves.length > 0) safeMoves = escapeMoves;
+
+  // With health to spare, a cluster of food is worth going a bit further for
+  const clusters = clusterSizes(gameState.board.food);
+  const clusterBonus = gameState.you.health > healthToSpare ? 1 : 0;
+
+  // Don't chase food someone else gets to first, unless there's nothing else.
+  // The food a greedy snake shorter than us is going for is always worth
+  // contesting: we take it first or win the head-to-head on it.
+  let targets = winnableFood(gameState);
+  if (targets.length === 0) targets = gameState.board.food;
+  const contested = new Set(greedy.map((g) => g.food));
+  targets = [...new Set([...targets, ...contested])];
+
+  const costs = {};
+  for (let move of safeMoves) {
+    const pos = possibleMoves[move].pos;
+    // Measure the actual path to each food, going around bodies and walls.
+    // Food we can't get to, or would starve on the way to, is out of reach.
+    const paths = healthMap(gameState, pos, healthAfter(gameState, pos, gameState.you.health));
+    for (let f of targets) {
+      // If the food right on a possible move, just go there
+      if (f.x === pos.x && f.y === pos.y) {
+        return respond(gameState, possibleMoves, move, "food");
+      }
+      const path = paths.get(`${f.x},${f.y}`);
+      if (path === undefined) continue;
+
+      let d = path.d;
+      d -= clusterBonus * (clusters.get(f) - 1);
+      if (contested.has(f)) d -= 2;
+      d += centerDistance(gameState, pos);
+      if (costs[move] === undefined || d < costs[move]) costs[move] = d;
+    }
+  }
+  if (Object.keys(costs).length > 0) {
+    const foodMove = pickMove(costs);
+    return respond(gameState, possibleMoves, foodMove, `nearest food, d: ${costs[foodMove]}`);
+  }
+
+  // Nothing to go for, head towards the center where there's the most room
+  const closest = Math.min(...safeMoves.map(move => centerDistance(gameState, possibleMoves[move].pos)));
+  const centerMoves = safeMoves.filter(move => centerDistance(gameState, possibleMoves[move].pos) === closest);
+  const nextMove = centerMoves[Math.floor(Math.random() * centerMoves.length)];
+  return respond(gameState, possibleMoves, nextMove, "center");
+}
+
+// Logs the chosen move and builds the response, adding a shout if configured
+function respond(gameState, possibleMoves, move, status) {
+  // Whatever got us here, never turn back into our own neck
+  const myNeck = neck(gameState.you);
+  const intoNeck = (m) => myNeck && possibleMoves[m].pos.x === myNeck.x && possibleMoves[m].pos.y === myNeck.y;
+  if (intoNeck(move)) {
+    move = Object.keys(possibleMoves).find(m => !intoNeck(m));
+  }
+
+  console.log(`MOVE ${gameState.turn}: ${move}, ${status}`);
+  explainMoves(gameState, possibleMoves);
+
+  const response = { move: move };
+  const message = shout(gameState, possibleMoves[move].pos, status);
+  if (message) response.shout = message;
+  return response;
+}
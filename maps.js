@@ -0,0 +1,14 @@
+// What we know about the maps on play.battlesnake.com, keyed on game.map.
+// Anything not listed plays like the standard map: hazards are sauce we can
+// tank for a few turns.
+const maps = {
+  // The maze walls are drawn with hazards and are never worth entering
+  arcade_maze: { hazardWalls: true },
+};
+
+const standard = { hazardWalls: false };
+
+export function mapInfo(gameState) {
+  return maps[gameState.game.map] ?? standard;
+}
+
@@ -0,0 +1,199 @@
+import { readFileSync } from 'node:fs';
+import { rename, writeFile } from 'node:fs/promises';
+import { distance } from './board.js';
+import { diffSnapshots, snapshot } from './snapshot.js';
+
+// Things we remember about each game we're playing between requests, keyed by
+// game id. Entries are created on the first request of a game and dropped when
+// it ends, or once we haven't heard about the game for a while in case its
+// /end never arrives.
+const games = new Map();
+
+// How long a game can go without a request before we forget it
+const idleTimeout = 10 * 60 * 1000;
+
+// Version of what checkpointGames writes. Bump it when what we remember changes
+// shape, and teach migrate() to bring older checkpoints up to date.
+const checkpointVersion = 1;
+
+// What we start out remembering about a game
+function blankMemory() {
+  return {
+    snakesAtStart: 0,
+    peakLength: 0,
+    previous: undefined,
+    foodEaten: 0,
+    eliminated: 0,
+    moveTimes: [],
+    shadowing: undefined,
+    foodChasing: new Map(),
+    hazardSpread: [],
+    memoryResets: 0,
+    lastSeen: undefined,
+  };
+}
+
+export function gameMemory(gameState) {
+  forgetIdleGames();
+  const id = gameState.game.id;
+  if (!games.has(id)) {
+    games.set(id, {
+      ...blankMemory(),
+      snakesAtStart: gameState.board.snakes.length,
+      peakLength: gameState.you.length,
+    });
+  }
+  const memory = games.get(id);
+  memory.lastSeen = Date.now();
+  return memory;
+}
+
+// Number of games we're currently remembering
+export function activeGames() {
+  forgetIdleGames();
+  return games.size;
+}
+
+// Writes everything we remember to file, so a restart in the middle of a game
+// can pick up where it left off. The file is written next to it first and
+// moved into place, a crash halfway through never leaves half a checkpoint.
+export async function checkpointGames(file) {
+  const saved = [...games].map(([id, memory]) => [id, {
+    ...memory,
+    previous: memory.previous && {
+      ...memory.previous,
+      snakes: [...memory.previous.snakes],
+      hazards: [...memory.previous.hazards],
+    },
+    foodChasing: [...memory.foodChasing],
+  }]);
+  try {
+    await writeFile(`${file}.tmp`, JSON.stringify({ version: checkpointVersion, games: saved }));
+    await rename(`${file}.tmp`, file);
+  } catch (error) {
+    console.log(`Could not checkpoint game memory to ${file}: ${error.message}`);
+  }
+}
+
+// Loads the games saved by checkpointGames, if there are any. Anything an
+// older build didn't save is filled in as if the game had just started, and
+// entries we can't make sense of are skipped. Games that went quiet while we
+// were down are forgotten like any other.
+export function restoreGames(file) {
+  let contents;
+  try {
+    contents = JSON.parse(readFileSync(file, "utf8"));
+  } catch (error) {
+    if (error.code !== "ENOENT") console.log(`Could not restore game memory from ${file}: ${error.message}`);
+    return;
+  }
+  const saved = migrate(contents);
+  if (saved === undefined) {
+    console.log(`Could not restore game memory from ${file}: not a checkpoint version ${checkpointVersion} can read`);
+    return;
+  }
+
+  for (let entry of saved) {
+    if (!Array.isArray(entry) || typeof entry[0] !== "string" || !isObject(entry[1])) continue;
+    const [id, memory] = entry;
+    const previous = memory.previous;
+    games.set(id, {
+      ...blankMemory(),
+      ...memory,
+      previous: isObject(previous) && Array.isArray(previous.snakes) && Array.isArray(previous.hazards) && Array.isArray(previous.food) ? {
+        ...previous,
+        snakes: new Map(previous.snakes),
+        hazards: new Set(previous.hazards),
+      } : undefined,
+      moveTimes: Array.isArray(memory.moveTimes) ? memory.moveTimes : [],
+      foodChasing: new Map(Array.isArray(memory.foodChasing) ? memory.foodChasing : []),
+      hazardSpread: Array.isArray(memory.hazardSpread) ? memory.hazardSpread : [],
+      lastSeen: typeof memory.lastSeen === "number" ? memory.lastSeen : Date.now(),
+    });
+  }
+  forgetIdleGames();
+}
+
+// Brings a checkpoint written by an older build up to the current version and
+// returns its list of games, or undefined if it's not one we know how to read
+function migrate(contents) {
+  // Before checkpoints had a version, they were just the list of games
+  if (Array.isArray(contents)) contents = { version: 1, games: contents };
+
+  if (!isObject(contents) || contents.version !== checkpointVersion || !Array.isArray(contents.games)) return undefined;
+  return contents.games;
+}
+
+function isObject(value) {
+  return typeof value === "object" && value !== null && !Array.isArray(value);
+}
+
+function forgetIdleGames() {
+  const now = Date.now();
+  for (let [id, memory] of games) {
+    if (now - memory.lastSeen > idleTimeout) games.delete(id);
+  }
+}
+
+export function forgetGame(gameState) {
+  games.delete(gameState.game.id);
+}
+
+// Compares the new turn with the last one we saw and keeps count of what
+// happened in between. If the two don't follow on from each other (a turn
+// went missing, or we restarted from an old checkpoint), what we've worked
+// out about the game so far can't be trusted and we start over from here.
+export function recordTurn(gameState) {
+  const memory = gameMemory(gameState);
+  const current = snapshot(gameState);
+  if (memory.previous !== undefined && !followsOn(gameState, memory.previous)) {
+    console.log(`MEMORY RESET: ${gameState.game.id} turn ${gameState.turn} doesn't follow on from turn ${memory.previous.turn}`);
+    const blank = blankMemory();
+    memory.previous = blank.previous;
+    memory.shadowing = blank.shadowing;
+    memory.foodChasing = blank.foodChasing;
+    memory.hazardSpread = blank.hazardSpread;
+    memory.memoryResets++;
+  }
+  if (memory.previous !== undefined) {
+    const changes = diffSnapshots(memory.previous, current);
+    if (changes.ate.includes(gameState.you.id)) memory.foodEaten++;
+    memory.eliminated += changes.died.filter((id) => id !== gameState.you.id).length;
+    recordFoodChasing(gameState, memory, memory.previous, current);
+    if (changes.newHazards.length > 0) {
+      memory.hazardSpread.push({ turn: gameState.turn, cells: changes.newHazards.length });
+    }
+  }
+  memory.previous = current;
+  memory.peakLength = Math.max(memory.peakLength, gameState.you.length);
+  return memory;
+}
+
+// True if this turn comes right after the previous snapshot and our head
+// moved one cell since. Once we're out there's nothing left to check.
+function followsOn(gameState, previous) {
+  const me = gameState.you;
+  if (!gameState.board.snakes.some((snake) => snake.id === me.id)) return true;
+
+  const before = previous.snakes.get(me.id);
+  return previous.turn === gameState.turn - 1 && before !== undefined && distance(gameState, before.head, me.head) === 1;
+}
+
+// Keeps count, for every opponent, of how many turns it had food to go for
+// and how many of those it moved straight towards the nearest one
+function recordFoodChasing(gameState, memory, before, after) {
+  if (before.food.length === 0) return;
+  for (let [id, snake] of before.snakes) {
+    const now = after.snakes.get(id);
+    if (id === gameState.you.id || now === undefined) continue;
+
+    const nearest = Math.min(...before.food.map((f) => distance(gameState, snake.head, f)));
+    const chased = before.food.some((f) =>
+      distance(gameState, snake.head, f) === nearest && distance(gameState, now.head, f) < nearest
+    );
+    const counts = memory.foodChasing.get(id) ?? { turns: 0, chased: 0 };
+    counts.turns++;
+    if (chased) counts.chased++;
+    memory.foodChasing.set(id, counts);
+  }
+}
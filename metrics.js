@@ -0,0 +1,128 @@
+import { gameMemory } from './memory.js';
+
+// Counters for the /metrics endpoint, in the Prometheus text format. They
+// live in memory and start from zero whenever the server restarts.
+const games = { started: 0 };
+
+// Finished games, counted by result, how the game ended for us, and the
+// format: ruleset, map, board size and how many snakes started
+const finished = new Map();
+
+// Running total of how long games lasted, per format, for the average
+const turns = new Map();
+
+// Running total of where we finished, for the average placement
+const placements = { sum: 0, count: 0 };
+
+// Upper bounds of the move latency histogram buckets, in milliseconds
+const buckets = [5, 10, 25, 50, 100, 250, 500];
+const latency = { counts: buckets.map(() => 0), sum: 0, count: 0, max: 0 };
+const quantiles = [0.5, 0.95, 0.99];
+
+export function recordGameStart() {
+  games.started++;
+}
+
+// Prometheus label values can't hold raw quotes, backslashes or newlines,
+// and the map name comes straight from the request
+function label(value) {
+  return String(value).replace(/\\/g, "\\\\").replace(/"/g, '\\"').replace(/\n/g, "\\n");
+}
+
+// Takes the GAME OVER summary, see end() in logic.js
+export function recordGameEnd(summary) {
+  const format = [
+    `ruleset="${label(summary.ruleset)}"`,
+    `map="${label(summary.map)}"`,
+    `board="${label(summary.board)}"`,
+    `players="${label(summary.snakesAtStart)}"`,
+  ].join(",");
+  const labels = `result="${label(summary.result)}",cause="${label(summary.cause)}",${format}`;
+  finished.set(labels, (finished.get(labels) ?? 0) + 1);
+
+  const lasted = turns.get(format) ?? { sum: 0, count: 0 };
+  lasted.sum += summary.turns;
+  lasted.count++;
+  turns.set(format, lasted);
+
+  placements.sum += summary.placement;
+  placements.count++;
+}
+
+// Times a move for the lifetime histogram and for this game's summary
+export function recordMoveLatency(gameState, ms) {
+  buckets.forEach((bound, i) => {
+    if (ms <= bound) latency.counts[i]++;
+  });
+  latency.sum += ms;
+  latency.count++;
+  latency.max = Math.max(latency.max, ms);
+  gameMemory(gameState).moveTimes.push(ms);
+}
+
+// p50, p95 and p99 of one game's move times, by nearest rank
+export function percentiles(times) {
+  const sorted = [...times].sort((a, b) => a - b);
+  const at = (q) => {
+    if (sorted.length === 0) return 0;
+    const rank = Math.ceil(q * sorted.length) - 1;
+    return Math.round(sorted[Math.max(rank, 0)] * 10) / 10;
+  };
+  return { p50: at(0.5), p95: at(0.95), p99: at(0.99) };
+}
+
+// Estimates a lifetime quantile from the histogram buckets rather than keeping
+// every move time we've ever seen, interpolating within the bucket it falls in
+function estimateQuantile(q) {
+  if (latency.count === 0) return 0;
+  const rank = q * latency.count;
+  for (let i = 0; i < buckets.length; i++) {
+    if (latency.counts[i] < rank) continue;
+    const lower = i === 0 ? 0 : buckets[i - 1];
+    const below = i === 0 ? 0 : latency.counts[i - 1];
+    const inBucket = latency.counts[i] - below;
+    const upper = Math.min(buckets[i], latency.max);
+    return lower + (upper - lower) * (rank - below) / inBucket;
+  }
+  return latency.max;
+}
+
+export function renderMetrics(activeGames) {
+  const lines = [
+    "# HELP battlesnake_games_started_total Games started.",
+    "# TYPE battlesnake_games_started_total counter",
+    `battlesnake_games_started_total ${games.started}`,
+    "# HELP battlesnake_games_finished_total Games finished, by result, cause, ruleset, map, board size and players.",
+    "# TYPE battlesnake_games_finished_total counter",
+    ...[...finished].map(([labels, count]) => `battlesnake_games_finished_total{${labels}} ${count}`),
+    "# HELP battlesnake_game_turns How many turns each game lasted, by ruleset, map, board size and players.",
+    "# TYPE battlesnake_game_turns summary",
+    ...[...turns].flatMap(([labels, lasted]) => [
+      `battlesnake_game_turns_sum{${labels}} ${lasted.sum}`,
+      `battlesnake_game_turns_count{${labels}} ${lasted.count}`,
+    ]),
+    "# HELP battlesnake_game_placement Where we finished in each game, 1 for first.",
+    "# TYPE battlesnake_game_placement summary",
+    `battlesnake_game_placement_sum ${placements.sum}`,
+    `battlesnake_game_placement_count ${placements.count}`,
+    "# HELP battlesnake_active_games Games currently being played.",
+    "# TYPE battlesnake_active_games gauge",
+    `battlesnake_active_games ${activeGames}`,
+    "# HELP battlesnake_move_duration_milliseconds Time spent answering /move.",
+    "# TYPE battlesnake_move_duration_milliseconds histogram",
+  ];
+  buckets.forEach((bound, i) => {
+    lines.push(`battlesnake_move_duration_milliseconds_bucket{le="${bound}"} ${latency.counts[i]}`);
+  });
+  lines.push(
+    `battlesnake_move_duration_milliseconds_bucket{le="+Inf"} ${latency.count}`,
+    `battlesnake_move_duration_milliseconds_sum ${latency.sum}`,
+    `battlesnake_move_duration_milliseconds_count ${latency.count}`,
+    "# HELP battlesnake_move_duration_quantile_milliseconds Estimated /move time quantiles since the server started.",
+    "# TYPE battlesnake_move_duration_quantile_milliseconds gauge",
+  );
+  quantiles.forEach((q) => {
+    lines.push(`battlesnake_move_duration_quantile_milliseconds{quantile="${q}"} ${estimateQuantile(q)}`);
+  });
+  return lines.join("\n") + "\n";
+}
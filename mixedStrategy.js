@@ -0,0 +1,25 @@
+// MOVE_TEMPERATURE above 0 makes us pick between moves that are about as good
+// at random instead of always taking the best one, so opponents that have
+// played us before can't predict us. 0 (the default) always takes the best.
+export function moveTemperature() {
+  const temperature = parseFloat(process.env.MOVE_TEMPERATURE);
+  return isNaN(temperature) ? 0 : Math.max(0, temperature);
+}
+
+// Picks from costs (move -> cost, lower is better). With a temperature each
+// move gets picked with a softmax probability, so moves that cost only a
+// little more than the best still have a fair chance.
+export function pickMove(costs) {
+  const moves = Object.keys(costs);
+  const best = Math.min(...moves.map(move => costs[move]));
+  const temperature = moveTemperature();
+  if (temperature === 0) return moves.find(move => costs[move] === best);
+
+  const weights = moves.map(move => Math.exp((best - costs[move]) / temperature));
+  let r = Math.random() * weights.reduce((a, b) => a + b, 0);
+  for (let i = 0; i < moves.length; i++) {
+    r -= weights[i];
+    if (r <= 0) return moves[i];
+  }
+  return moves[moves.length - 1];
+}
@@ -0,0 +1,49 @@
+import { mapInfo } from './maps.js';
+
+// Lookups for what's on each cell of the board, built once per game state
+// instead of scanning the snakes, food and hazards every time we ask
+const cache = new WeakMap();
+
+export function occupancy(gameState) {
+  if (cache.has(gameState)) return cache.get(gameState);
+
+  const blocked = new Set();
+  gameState.board.snakes.forEach((snake) => {
+    for (let part of snake.body) {
+      blocked.add(`${part.x},${part.y}`);
+    }
+  });
+
+  const food = new Set(gameState.board.food.map((f) => `${f.x},${f.y}`));
+
+  // Some maps stack several hazards on one cell, so count them
+  const hazards = new Map();
+  for (let h of gameState.board.hazards) {
+    const key = `${h.x},${h.y}`;
+    hazards.set(key, (hazards.get(key) ?? 0) + 1);
+    if (hazardsAreWalls(gameState)) blocked.add(key);
+  }
+
+  const cells = { blocked: blocked, food: food, hazards: hazards };
+  cache.set(gameState, cells);
+  return cells;
+}
+
+export function isFood(gameState, pos) {
+  return occupancy(gameState).food.has(`${pos.x},${pos.y}`);
+}
+
+// Number of hazards stacked on pos
+export function hazardCount(gameState, pos) {
+  return occupancy(gameState).hazards.get(`${pos.x},${pos.y}`) ?? 0;
+}
+
+// Set of "x,y" keys for every blocked cell, a copy callers are free to change
+export function blockedCells(gameState) {
+  return new Set(occupancy(gameState).blocked);
+}
+
+// On maps like arcade_maze the hazards are walls, they are not worth tanking
+export function hazardsAreWalls(gameState) {
+  return mapInfo(gameState).hazardWalls;
+}
@@ -0,0 +1,28 @@
+import { isWrapped } from './board.js';
+import { floodFill } from './floodFill.js';
+import { predictedLength } from './headDanger.js';
+
+// Checks if moving to pos pins a shorter opponent against the edge of the
+// board: its head is on the edge, we're right beside it on the inside, and
+// the move takes away some of the room it has left
+export function pinsToWall(gameState, pos) {
+  if (isWrapped(gameState)) return false;
+
+  const me = gameState.you;
+  const boardWidth = gameState.board.width;
+  const boardHeight = gameState.board.height;
+
+  return gameState.board.snakes.some((snake) => {
+    if (snake.id === me.id || predictedLength(gameState, snake) >= me.length) return false;
+
+    const head = snake.head;
+    const alongside =
+      (head.x === 0 && pos.x === 1 && Math.abs(pos.y - head.y) <= 1) ||
+      (head.x === boardWidth - 1 && pos.x === boardWidth - 2 && Math.abs(pos.y - head.y) <= 1) ||
+      (head.y === 0 && pos.y === 1 && Math.abs(pos.x - head.x) <= 1) ||
+      (head.y === boardHeight - 1 && pos.y === boardHeight - 2 && Math.abs(pos.x - head.x) <= 1);
+    if (!alongside) return false;
+
+    return floodFill(gameState, head, [pos]) < floodFill(gameState, head);
+  });
+}
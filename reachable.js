@@ -0,0 +1,42 @@
+import { neighbours } from './board.js';
+import { floodRegion } from './floodFill.js';
+import { blockedCells, isFood } from './occupancy.js';
+import { tail } from './snake.js';
+
+// Looks at the free space we're left with after moving our head to pos. Unlike
+// a flood fill on the current board, this moves our body forward first: the
+// tail frees up its cell unless we're about to grow. Returns how many cells
+// we can reach and whether our tail borders them, in which case we can follow
+// it around no matter how small the region is.
+export function regionAfterMove(gameState, pos) {
+  const me = gameState.you;
+  const eats = isFood(gameState, pos);
+
+  const body = [pos, ...me.body];
+  if (!eats) body.pop();
+
+  const moved = { ...me, head: pos, body: body };
+  const next = {
+    ...gameState,
+    board: {
+      ...gameState.board,
+      snakes: gameState.board.snakes.map((snake) => snake.id === me.id ? moved : snake),
+    },
+    you: moved,
+  };
+
+  // If pos is an articulation point of the free space, stepping on it splits
+  // the board and we only get to keep one side. Look at each side separately
+  // instead of flooding through pos and adding them up.
+  const walls = blockedCells(next);
+  const tailNeighbours = neighbours(gameState, tail(moved)).map((n) => `${n.x},${n.y}`);
+  let best = { room: 0, hasTail: false };
+  for (let n of neighbours(gameState, pos)) {
+    if (walls.has(`${n.x},${n.y}`)) continue;
+    const region = floodRegion(next, n);
+    const side = { room: region.size, hasTail: tailNeighbours.some((key) => region.has(key)) };
+    if (side.room > best.room || (side.room === best.room && side.hasTail)) best = side;
+  }
+  return best;
+}
+
@@ -0,0 +1,28 @@
+// Draws the board as text, top row first like on play.battlesnake.com.
+// We're @ for the head and o for the body, opponents get a letter each
+// (upper case for the head), food is * and hazards are ~.
+export function renderBoard(gameState) {
+  const boardWidth = gameState.board.width;
+  const boardHeight = gameState.board.height;
+  const rows = [];
+  for (let y = 0; y < boardHeight; y++) {
+    rows.push(new Array(boardWidth).fill("."));
+  }
+  const put = (pos, c) => {
+    if (pos.x >= 0 && pos.y >= 0 && pos.x < boardWidth && pos.y < boardHeight) rows[pos.y][pos.x] = c;
+  };
+
+  for (let h of gameState.board.hazards) put(h, "~");
+  for (let f of gameState.board.food) put(f, "*");
+
+  let letter = 0;
+  for (let snake of gameState.board.snakes) {
+    const mine = snake.id === gameState.you.id;
+    const c = mine ? "o" : String.fromCharCode(97 + letter++ % 26);
+    // Draw tail first so the head ends up on top of stacked segments
+    for (let part of [...snake.body].reverse()) put(part, c);
+    put(snake.body[0], mine ? "@" : c.toUpperCase());
+  }
+
+  return rows.reverse().map((row) => row.join(" ")).join("\n");
+}
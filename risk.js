@@ -0,0 +1,8 @@
+// RISK_TOLERANCE, between 0 and 1, sets how much we gamble on dangers that
+// might not happen (head-to-heads we'd trade, hazard crossings with little
+// health to spare). 0 plays it safe, 1 ignores them.
+export function riskTolerance() {
+  const risk = parseFloat(process.env.RISK_TOLERANCE);
+  if (isNaN(risk)) return 0;
+  return Math.min(1, Math.max(0, risk));
+}
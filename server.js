@@ -1,4 +1,7 @@
 import express from 'express';
+import { withDefaults } from './defaults.js';
+import { recordMoveLatency } from './metrics.js';
+import { validateGameState } from './validate.js';
 
 export default function runServer(handlers) {
   const app = express();
@@ -8,20 +11,40 @@ export default function runServer(handlers) {
     res.send(handlers.info());
   });
 
-  app.post("/start", (req, res) => {
-    handlers.start(req.body);
+  // Turns away requests the logic can't make sense of with a 400 saying why,
+  // rather than letting them blow up somewhere inside it
+  const validate = (alive) => (req, res, next) => {
+    const error = validateGameState(req.body, alive);
+    if (error) {
+      res.status(400).send({ error: error });
+      return;
+    }
+    next();
+  };
+
+  app.post("/start", validate(true), (req, res) => {
+    handlers.start(withDefaults(req.body));
     res.send("ok");
   });
 
-  app.post("/move", (req, res) => {
-    res.send(handlers.move(req.body));
+  app.post("/move", validate(true), (req, res) => {
+    const started = performance.now();
+    const gameState = withDefaults(req.body);
+    const response = handlers.move(gameState);
+    recordMoveLatency(gameState, performance.now() - started);
+    res.send(response);
   });
 
-  app.post("/end", (req, res) => {
-    handlers.end(req.body);
+  app.post("/end", validate(false), (req, res) => {
+    handlers.end(withDefaults(req.body));
     res.send("ok");
   });
 
+  app.get("/metrics", (req, res) => {
+    res.set("Content-Type", "text/plain; version=0.0.4");
+    res.send(handlers.metrics());
+  });
+
   app.use(function(req, res, next) {
     res.set("Server", "battlesnake/replit/starter-snake-javascript");
     next();
@@ -0,0 +1,46 @@
+import { distance } from './board.js';
+import { predictedLength } from './headDanger.js';
+import { gameMemory } from './memory.js';
+
+// We shadow opponents we're at most this much longer than. Much bigger than
+// them, going for the kill pays off more than hanging around.
+const lead = 2;
+
+// Heads this many moves apart are close enough to start shadowing, and one
+// more than that to keep at it
+const reach = 3;
+
+// The opponent we're shadowing: the same one as last turn if it's still close
+// by and shorter than us, otherwise the nearest one that is. It's remembered
+// between turns so we stick with one target instead of switching around.
+export function shadowTarget(gameState) {
+  const me = gameState.you;
+  const memory = gameMemory(gameState);
+  const shorter = (snake) => {
+    const gap = me.length - predictedLength(gameState, snake);
+    return snake.id !== me.id && gap >= 1 && gap <= lead;
+  };
+
+  const current = gameState.board.snakes.find((snake) => snake.id === memory.shadowing);
+  if (current && shorter(current) && distance(gameState, me.head, current.head) <= reach + 1) return current;
+
+  const candidates = gameState.board.snakes
+    .filter((snake) => shorter(snake) && distance(gameState, me.head, snake.head) <= reach)
+    .sort((a, b) => distance(gameState, me.head, a.head) - distance(gameState, me.head, b.head));
+  memory.shadowing = candidates[0]?.id;
+  return candidates[0];
+}
+
+// Moves that put our head diagonally next to the head of the snake we're
+// shadowing. From there we take away two of the cells it could move to, and
+// it can't reach our head in one move to trade.
+export function shadowMoves(gameState, possibleMoves, safeMoves) {
+  const target = shadowTarget(gameState);
+  if (target === undefined) return [];
+
+  const head = target.head;
+  return safeMoves.filter((move) => {
+    const pos = possibleMoves[move].pos;
+    return distance(gameState, pos, head) === 2 && pos.x !== head.x && pos.y !== head.y;
+  });
+}
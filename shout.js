@@ -0,0 +1,39 @@
+import { distance } from './board.js';
+import { cutsOff } from './cutOff.js';
+import { predictedLength } from './headDanger.js';
+import { isKillMove } from './killMove.js';
+
+// Shouts are controlled with the SHOUT_MODE environment variable:
+//   silent - never shout (default)
+//   status - shout a short note on why the move was picked
+//   taunt  - shout only when the move goes for a kill
+const taunts = [
+  "Nom nom nom",
+  "Nowhere to run",
+  "Size matters",
+  "Say hello to the caterpillar",
+];
+
+export function shout(gameState, pos, status) {
+  const mode = process.env.SHOUT_MODE || "silent";
+
+  if (mode === "status") {
+    // The API truncates shouts longer than 256 characters
+    return status.slice(0, 256);
+  }
+
+  if (mode === "taunt" && (isKillMove(gameState, pos) || threatensShorterHead(gameState, pos) || cutsOff(gameState, pos))) {
+    return taunts[Math.floor(Math.random() * taunts.length)];
+  }
+
+  return undefined;
+}
+
+// True if a shorter snake could move its head onto pos next turn
+function threatensShorterHead(gameState, pos) {
+  const me = gameState.you;
+  return gameState.board.snakes.some((snake) => {
+    if (snake.id === me.id || predictedLength(gameState, snake) >= me.length) return false;
+    return distance(gameState, snake.head, pos) === 1;
+  });
+}
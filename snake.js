@@ -0,0 +1,22 @@
+import { distance } from './board.js';
+
+// Small helpers for reading a snake from the request
+
+// The segment right behind the head, undefined for a snake of length 1
+export function neck(snake) {
+  return snake.body.length > 1 ? snake.body[1] : undefined;
+}
+
+export function tail(snake) {
+  return snake.body[snake.body.length - 1];
+}
+
+// Health is topped back up to 100 on the turn a snake eats
+export function justAte(snake) {
+  return snake.health === 100;
+}
+
+// A snake with its head next to food will most likely eat it this turn
+export function willGrow(gameState, snake) {
+  return gameState.board.food.some((f) => distance(gameState, f, snake.head) === 1);
+}
@@ -0,0 +1,27 @@
+// Keeps what we need from a turn to compare it with the next one
+export function snapshot(gameState) {
+  const snakes = new Map();
+  for (let snake of gameState.board.snakes) {
+    snakes.set(snake.id, { name: snake.name, length: snake.length, health: snake.health, head: snake.head });
+  }
+  return {
+    turn: gameState.turn,
+    snakes: snakes,
+    food: gameState.board.food,
+    hazards: new Set(gameState.board.hazards.map((h) => `${h.x},${h.y}`)),
+  };
+}
+
+// What happened between two snapshots: who ate (grew), who's gone from the
+// board, and which cells turned into hazards
+export function diffSnapshots(before, after) {
+  const ate = [];
+  const died = [];
+  for (let [id, snake] of before.snakes) {
+    const now = after.snakes.get(id);
+    if (now === undefined) died.push(id);
+    else if (now.length > snake.length) ate.push(id);
+  }
+  const newHazards = [...after.hazards].filter((key) => !before.hazards.has(key));
+  return { ate: ate, died: died, newHazards: newHazards };
+}
@@ -0,0 +1,22 @@
+// With shared health or length on, our squad eats and grows as one: a
+// squadmate eating heals us too. Returns the game state as we should judge it,
+// with our health and length swapped for the squad's.
+export function squadView(gameState) {
+  const settings = gameState.game.ruleset.settings.squad;
+  const me = gameState.you;
+  if (!settings || !me.squad || !(settings.sharedHealth || settings.sharedLength)) return gameState;
+
+  const squad = gameState.board.snakes.filter((snake) => snake.squad === me.squad);
+  const shared = { ...me };
+  if (settings.sharedHealth) shared.health = Math.max(...squad.map((snake) => snake.health));
+  if (settings.sharedLength) shared.length = Math.max(...squad.map((snake) => snake.length));
+
+  return {
+    ...gameState,
+    board: {
+      ...gameState.board,
+      snakes: gameState.board.snakes.map((snake) => snake.id === me.id ? shared : snake),
+    },
+    you: shared,
+  };
+}
@@ -0,0 +1,38 @@
+import { distance } from './board.js';
+import { distanceMap } from './distanceMap.js';
+import { isFood } from './occupancy.js';
+import { tail } from './snake.js';
+
+// Health we keep in hand on top of the trip to the nearest food
+const hungerMargin = 10;
+
+// When survival is the only goal we stall by chasing our own tail: the cells
+// behind it are always the next to free up, so we can go round in a loop for
+// as long as our health lasts. Every piece of food makes us longer and the
+// loop harder, so we only break off to eat when we have to.
+export function shouldStall(gameState) {
+  if (gameState.board.snakes.length !== 1) return false;
+
+  const distances = distanceMap(gameState, gameState.you.head);
+  const food = gameState.board.food.map((f) => distances.get(`${f.x},${f.y}`) ?? Infinity);
+  const nearest = Math.min(...food);
+  return nearest === Infinity || gameState.you.health > nearest + hungerMargin;
+}
+
+// Picks the move that keeps our head closest to our tail, stepping around
+// food if we can
+export function stallMove(gameState, possibleMoves, safeMoves) {
+  const myTail = tail(gameState.you);
+
+  let best = safeMoves[0];
+  let bestScore = Infinity;
+  for (let move of safeMoves) {
+    const pos = possibleMoves[move].pos;
+    const score = distance(gameState, pos, myTail) + (isFood(gameState, pos) ? gameState.board.width * gameState.board.height : 0);
+    if (score < bestScore) {
+      best = move;
+      bestScore = score;
+    }
+  }
+  return best;
+}
@@ -0,0 +1,39 @@
+import { neighbours } from './board.js';
+import { blockedCells, isFood } from './occupancy.js';
+import { healthAfter } from './healthProjection.js';
+
+// How many turns we look ahead when checking if we're already doomed
+export const survivalHorizon = 10;
+
+// Plays out our own moves from pos for up to `depth` turns, with the other
+// snakes standing still, and returns how many turns the longest line lasts
+// before we run into something or starve
+export function survivalDepth(gameState, pos, depth = survivalHorizon) {
+  const me = gameState.you;
+  const walls = blockedCells(gameState);
+  for (let part of me.body) {
+    walls.delete(`${part.x},${part.y}`);
+  }
+
+  const search = (body, health, turns) => {
+    if (turns === depth) return turns;
+    let best = turns;
+    for (let n of neighbours(gameState, body[0])) {
+      const grows = isFood(gameState, n);
+      const rest = grows ? body : body.slice(0, -1);
+      if (walls.has(`${n.x},${n.y}`) || rest.some((part) => part.x === n.x && part.y === n.y)) continue;
+
+      const left = healthAfter(gameState, n, health);
+      if (left <= 0) continue;
+
+      best = Math.max(best, search([n, ...rest], left, turns + 1));
+      if (best === depth) break;
+    }
+    return best;
+  };
+
+  const first = healthAfter(gameState, pos, me.health);
+  if (first <= 0) return 0;
+  const body = isFood(gameState, pos) ? [pos, ...me.body] : [pos, ...me.body.slice(0, -1)];
+  return search(body, first, 1);
+}
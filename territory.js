@@ -0,0 +1,34 @@
+import { distanceMap } from './distanceMap.js';
+
+// Works out, for every cell, how many moves the closest snake needs to reach
+// it and which snakes get there in that many moves. Built once per turn and
+// shared by everything that needs to know who owns which part of the board.
+const cache = new WeakMap();
+
+export function territory(gameState) {
+  if (cache.has(gameState)) return cache.get(gameState);
+
+  const cells = new Map();
+  for (let snake of gameState.board.snakes) {
+    for (let [key, d] of distanceMap(gameState, snake.head)) {
+      const cell = cells.get(key);
+      if (cell === undefined || d < cell.d) {
+        cells.set(key, { d: d, snakes: [snake] });
+      } else if (d === cell.d) {
+        cell.snakes.push(snake);
+      }
+    }
+  }
+
+  cache.set(gameState, cells);
+  return cells;
+}
+
+// True if we get to pos before anyone else, or at the same time as snakes we
+// would beat head-to-head
+export function ownsCell(gameState, pos) {
+  const me = gameState.you;
+  const cell = territory(gameState).get(`${pos.x},${pos.y}`);
+  if (cell === undefined || !cell.snakes.some((snake) => snake.id === me.id)) return false;
+  return cell.snakes.every((snake) => snake.id === me.id || snake.length < me.length);
+}
@@ -0,0 +1,84 @@
+// Builds game states for tests without writing out the whole request:
+//
+//   new GameStateBuilder(11, 11).snake("me", [[5, 5], [5, 4]]).food(3, 3).build()
+//
+// Bodies are lists of [x, y] from head to tail. The first snake added is `you`.
+export class GameStateBuilder {
+  constructor(width, height) {
+    this.width = width;
+    this.height = height;
+    this.snakes = [];
+    this.foods = [];
+    this.hazards = [];
+    this.turnNumber = 0;
+    this.rulesetName = "standard";
+    this.settings = {};
+    this.mapName = "standard";
+  }
+
+  snake(id, body, health = 90) {
+    const parts = body.map(([x, y]) => ({ x: x, y: y }));
+    this.snakes.push({
+      id: id,
+      name: id,
+      health: health,
+      body: parts,
+      head: parts[0],
+      length: parts.length,
+      latency: "100",
+      shout: "",
+    });
+    return this;
+  }
+
+  food(x, y) {
+    this.foods.push({ x: x, y: y });
+    return this;
+  }
+
+  hazard(x, y) {
+    this.hazards.push({ x: x, y: y });
+    return this;
+  }
+
+  turn(turn) {
+    this.turnNumber = turn;
+    return this;
+  }
+
+  ruleset(name, settings = {}) {
+    this.rulesetName = name;
+    this.settings = settings;
+    return this;
+  }
+
+  map(name) {
+    this.mapName = name;
+    return this;
+  }
+
+  build() {
+    return {
+      game: {
+        id: "test-game",
+        ruleset: {
+          name: this.rulesetName,
+          version: "v1.0.0",
+          settings: { foodSpawnChance: 15, minimumFood: 1, hazardDamagePerTurn: 14, ...this.settings },
+        },
+        map: this.mapName,
+        timeout: 500,
+        source: "",
+      },
+      turn: this.turnNumber,
+      board: {
+        width: this.width,
+        height: this.height,
+        food: this.foods,
+        hazards: this.hazards,
+        snakes: this.snakes,
+      },
+      you: this.snakes[0],
+    };
+  }
+}
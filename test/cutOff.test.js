@@ -0,0 +1,28 @@
+import assert from "node:assert/strict";
+import { before, mock, test } from "node:test";
+
+import { cutsOff } from "../cutOff.js";
+import { move } from "../logic.js";
+import { GameStateBuilder } from "./builder.js";
+
+before(() => {
+  mock.method(console, "log", () => {});
+});
+
+// Moving up seals the other snake into the top left corner, moving right eats
+function position(health) {
+  return new GameStateBuilder(11, 11)
+    .snake("me", [[2, 9], [2, 8], [1, 8], [1, 7], [1, 6], [1, 5], [1, 4]], health)
+    .snake("cornered", [[0, 9], [0, 8], [0, 7], [0, 6], [0, 5]])
+    .food(3, 9)
+    .build();
+}
+
+test("with health to spare we seal a snake into a corner", () => {
+  assert.ok(cutsOff(position(80), { x: 2, y: 10 }));
+  assert.equal(move(position(80)).move, "up");
+});
+
+test("low on health we eat before going for a cut-off", () => {
+  assert.equal(move(position(20)).move, "right");
+});
@@ -0,0 +1,60 @@
+import assert from "node:assert/strict";
+import { test } from "node:test";
+
+import { deathCause } from "../deathCause.js";
+import { recordGameEnd, renderMetrics } from "../metrics.js";
+import { snapshot } from "../snapshot.js";
+import { GameStateBuilder } from "./builder.js";
+
+test("a move that didn't come back in time is reported with the game's timeout as latency", () => {
+  const gameState = new GameStateBuilder(11, 11).snake("me", [[5, 5], [5, 4], [5, 3]]).build();
+  gameState.you.latency = "500";
+  gameState.board.snakes = [];
+  assert.equal(deathCause(gameState), "timed-out");
+});
+
+test("a snake that timed out is reported as such even though it then hit a wall", () => {
+  const gameState = new GameStateBuilder(11, 11).snake("me", [[5, 11], [5, 10], [5, 9]]).build();
+  gameState.you.latency = "500";
+  gameState.board.snakes = [];
+  assert.equal(deathCause(gameState), "timed-out");
+});
+
+test("a head-to-head with a snake our length is spotted from the turn before", () => {
+  const before = new GameStateBuilder(11, 11)
+    .snake("me", [[5, 5], [5, 4], [5, 3]])
+    .snake("rival", [[5, 7], [5, 8], [5, 9]])
+    .build();
+
+  const after = new GameStateBuilder(11, 11)
+    .snake("me", [[5, 6], [5, 5], [5, 4]])
+    .snake("third", [[0, 0], [0, 1], [0, 2]])
+    .build();
+  after.board.snakes = after.board.snakes.filter((snake) => snake.id !== "me");
+
+  assert.equal(deathCause(after, snapshot(before)), "head-to-head");
+  assert.equal(deathCause(after), "unknown");
+});
+
+test("finished games are counted by result, cause and format", () => {
+  const summary = {
+    result: "loss", cause: "starved", placement: 2, turns: 100,
+    ruleset: "royale", map: "royale", board: "11x11", snakesAtStart: 4,
+  };
+  recordGameEnd(summary);
+  recordGameEnd({ ...summary, turns: 50 });
+  recordGameEnd({ ...summary, ruleset: "standard", map: "standard" });
+  recordGameEnd({ ...summary, board: "7x7", snakesAtStart: 2 });
+
+  const metrics = renderMetrics(0);
+  assert.match(metrics, /^battlesnake_games_finished_total\{result="loss",cause="starved",ruleset="royale",map="royale",board="11x11",players="4"\} 2$/m);
+  assert.match(metrics, /^battlesnake_games_finished_total\{result="loss",cause="starved",ruleset="standard",map="standard",board="11x11",players="4"\} 1$/m);
+  assert.match(metrics, /^battlesnake_games_finished_total\{result="loss",cause="starved",ruleset="royale",map="royale",board="7x7",players="2"\} 1$/m);
+  assert.match(metrics, /^battlesnake_game_turns_sum\{ruleset="royale",map="royale",board="11x11",players="4"\} 150$/m);
+  assert.match(metrics, /^battlesnake_game_turns_count\{ruleset="royale",map="royale",board="11x11",players="4"\} 2$/m);
+});
+
+test("a map name with quotes in it can't break the metrics", () => {
+  recordGameEnd({ result: "win", cause: "survived", placement: 1, turns: 10, ruleset: "standard", map: 'my "map"', board: "11x11", snakesAtStart: 2 });
+  assert.match(renderMetrics(0), /^battlesnake_games_finished_total\{result="win",cause="survived",ruleset="standard",map="my \\"map\\"",board="11x11",players="2"\} 1$/m);
+});
@@ -0,0 +1,20 @@
+import assert from "node:assert/strict";
+import { test } from "node:test";
+
+import { withDefaults } from "../defaults.js";
+import { healthAfter } from "../healthProjection.js";
+import { GameStateBuilder } from "./builder.js";
+
+test("a ruleset without hazard damage gets the engine's 14", () => {
+  const request = new GameStateBuilder(11, 11).snake("me", [[5, 5], [5, 4], [5, 3]]).hazard(5, 6).build();
+  delete request.game.ruleset.settings.hazardDamagePerTurn;
+
+  const gameState = withDefaults(request);
+  assert.equal(gameState.game.ruleset.settings.hazardDamagePerTurn, 14);
+  assert.equal(healthAfter(gameState, { x: 5, y: 6 }, 90), 90 - 15);
+});
+
+test("hazard damage the ruleset sets is kept, even when it's none", () => {
+  const request = new GameStateBuilder(11, 11).ruleset("royale", { hazardDamagePerTurn: 0 }).build();
+  assert.equal(withDefaults(request).game.ruleset.settings.hazardDamagePerTurn, 0);
+});
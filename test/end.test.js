@@ -0,0 +1,58 @@
+import assert from "node:assert/strict";
+import { before, mock, test } from "node:test";
+
+import { end, move } from "../logic.js";
+import { renderMetrics } from "../metrics.js";
+import { GameStateBuilder } from "./builder.js";
+
+let logs;
+before(() => {
+  logs = mock.method(console, "log", () => {});
+});
+
+function summary() {
+  const line = logs.mock.calls.map((call) => call.arguments[0]).findLast((l) => l.startsWith("GAME OVER: "));
+  return JSON.parse(line.slice("GAME OVER: ".length));
+}
+
+test("going out while four snakes are left counts as fourth place", () => {
+  const lastTurn = new GameStateBuilder(11, 11)
+    .turn(40)
+    .snake("me", [[5, 5], [5, 4], [5, 3]])
+    .snake("a", [[0, 0], [0, 1], [0, 2]])
+    .snake("b", [[10, 0], [10, 1], [10, 2]])
+    .snake("c", [[10, 10], [10, 9], [10, 8]])
+    .build();
+  move(lastTurn);
+
+  const final = new GameStateBuilder(11, 11)
+    .turn(90)
+    .snake("me", [[5, 11], [5, 10], [5, 9]])
+    .snake("c", [[3, 3], [3, 4], [3, 5]])
+    .build();
+  final.board.snakes = final.board.snakes.filter((snake) => snake.id !== "me");
+  end(final);
+
+  assert.equal(summary().placement, 4);
+  assert.match(renderMetrics(0), /^battlesnake_game_placement_count 1$/m);
+});
+
+test("the summary records the settings that change play, and a hash that changes with them", () => {
+  const finish = () => {
+    const final = new GameStateBuilder(11, 11).turn(1).snake("me", [[5, 5], [5, 4], [5, 3]]).build();
+    end(final);
+    return summary();
+  };
+
+  process.env.RISK_TOLERANCE = "0.5";
+  const risky = finish();
+  delete process.env.RISK_TOLERANCE;
+  const careful = finish();
+
+  assert.equal(risky.config.riskTolerance, 0.5);
+  assert.equal(careful.config.riskTolerance, 0);
+  assert.equal(careful.config.hazardThreshold, 40);
+  assert.equal(careful.config.moveTemperature, 0);
+  assert.match(careful.configHash, /^[0-9a-f]{8}$/);
+  assert.notEqual(risky.configHash, careful.configHash);
+});
@@ -0,0 +1,43 @@
+import assert from "node:assert/strict";
+import { before, mock, test } from "node:test";
+
+import { greedySnakes } from "../greedy.js";
+import { move } from "../logic.js";
+import { forgetGame } from "../memory.js";
+import { GameStateBuilder } from "./builder.js";
+
+before(() => {
+  mock.method(console, "log", () => {});
+});
+
+// The way we come up to wait below row 9, reaching (4, 8) on turn 5
+const ourPath = [[3, 0], [4, 0], [4, 1], [4, 2], [4, 3], [4, 4], [4, 5], [4, 6], [4, 7], [4, 8]];
+
+// Turn t, with the other snake's head at 10 - t on row 9, heading left for the
+// food one cell a turn
+function turn(t) {
+  const x = 10 - t;
+  return new GameStateBuilder(13, 11)
+    .turn(t)
+    .snake("me", ourPath.slice(t, t + 5).reverse(), 80)
+    .snake("greedy", [[x, 9], [x + 1, 9], [x + 2, 9]])
+    .food(1, 9)
+    .build();
+}
+
+test("a shorter snake that keeps going straight for food is met head-on where it steps next", () => {
+  forgetGame(turn(0));
+  for (let t = 0; t < 5; t++) move(turn(t));
+
+  const gameState = turn(5);
+  assert.equal(move(gameState).move, "up");
+  const greedy = greedySnakes(gameState);
+  assert.equal(greedy.length, 1);
+  assert.deepEqual(greedy[0].next, { x: 4, y: 9 });
+});
+
+test("a snake we haven't watched for long enough isn't greedy yet", () => {
+  forgetGame(turn(0));
+  for (let t = 0; t < 3; t++) move(turn(t));
+  assert.deepEqual(greedySnakes(turn(3)), []);
+});
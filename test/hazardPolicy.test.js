@@ -0,0 +1,66 @@
+import assert from "node:assert/strict";
+import { before, mock, test } from "node:test";
+
+import { move } from "../logic.js";
+import { forgetGame } from "../memory.js";
+import { GameStateBuilder } from "./builder.js";
+
+before(() => {
+  mock.method(console, "log", () => {});
+});
+
+test("with no food on the board there's no reason to step into a hazard", () => {
+  const gameState = new GameStateBuilder(11, 11)
+    .ruleset("royale")
+    .snake("me", [[5, 4], [5, 3], [5, 2]])
+    .snake("other", [[0, 10], [1, 10], [2, 10]])
+    .hazard(5, 5)
+    .build();
+  assert.notEqual(move(gameState).move, "up");
+});
+
+test("a hazard shortcut is judged on the health left at the far side, not after the first cell", () => {
+  // Straight across is four hazards to the food, around is fourteen moves.
+  // We'd have 60 health after the first hazard but only 15 after the last.
+  const builder = new GameStateBuilder(11, 11)
+    .ruleset("royale")
+    .snake("me", [[0, 5], [0, 4], [0, 3]], 75)
+    .snake("other", [[10, 0], [10, 1], [10, 2]])
+    .food(5, 5);
+  for (let x = 1; x <= 4; x++) {
+    for (let y = 0; y <= 9; y++) builder.hazard(x, y);
+  }
+  assert.equal(move(builder.build()).move, "up");
+});
+
+// A royale turn where we're heading up the left edge, reaching (0, 5) on turn
+// 30. There's a wall of hazards between us and the food there, two moves
+// shorter to cross than to go around, and the right side of the board is
+// swallowed once `closing` is set.
+function royaleTurn(turn, closing) {
+  const y = turn - 25;
+  const builder = new GameStateBuilder(11, 11)
+    .ruleset("royale")
+    .turn(turn)
+    .snake("me", [[0, y], [0, y - 1], [0, y - 2]], 100)
+    .snake("other", [[6, 10], [6, 9], [6, 8]])
+    .food(3, 5);
+  for (let y = 0; y <= 5; y++) builder.hazard(1, y);
+  if (closing) {
+    for (let x = 8; x <= 10; x++) {
+      for (let y = 0; y <= 10; y++) builder.hazard(x, y);
+    }
+  }
+  return builder.build();
+}
+
+test("a small hazard shortcut isn't worth it while the hazards hold still", () => {
+  forgetGame(royaleTurn(0, false));
+  assert.equal(move(royaleTurn(30, true)).move, "up");
+});
+
+test("once the hazards are closing in fast, a small shortcut to food is worth it", () => {
+  forgetGame(royaleTurn(0, false));
+  move(royaleTurn(29, false));
+  assert.equal(move(royaleTurn(30, true)).move, "right");
+});
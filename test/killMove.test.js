@@ -0,0 +1,30 @@
+import assert from "node:assert/strict";
+import { before, mock, test } from "node:test";
+
+import { isKillMove } from "../killMove.js";
+import { move } from "../logic.js";
+import { GameStateBuilder } from "./builder.js";
+
+before(() => {
+  mock.method(console, "log", () => {});
+});
+
+test("taking the last cell a shorter snake can move to is a kill", () => {
+  // Its only other way out, (1, 5), is our body
+  const gameState = new GameStateBuilder(11, 11)
+    .snake("me", [[1, 6], [1, 5], [1, 4], [1, 3], [1, 2]])
+    .snake("prey", [[0, 5], [0, 4], [0, 3]])
+    .build();
+  assert.ok(isKillMove(gameState, { x: 0, y: 6 }));
+  assert.ok(!isKillMove(gameState, { x: 1, y: 7 }));
+});
+
+test("a shorter snake following its own tail isn't ours to kill", () => {
+  const gameState = new GameStateBuilder(11, 11)
+    .snake("me", [[5, 5], [5, 4], [5, 3], [5, 2], [5, 1]], 50)
+    .snake("curled", [[0, 0], [1, 0], [1, 1], [0, 1]])
+    .food(6, 5)
+    .build();
+  assert.ok(!isKillMove(gameState, { x: 5, y: 6 }));
+  assert.equal(move(gameState).move, "right");
+});
@@ -0,0 +1,63 @@
+import assert from "node:assert/strict";
+import { before, mock, test } from "node:test";
+
+import { distanceMap } from "../distanceMap.js";
+import { move } from "../logic.js";
+import { GameStateBuilder } from "./builder.js";
+
+// Walls on every odd column, open at the top and bottom in turn, so the only
+// way across the board snakes up and down every column in between
+function serpentine(size) {
+  const walls = [];
+  for (let x = 1; x < size - 1; x += 2) {
+    const gap = ((x - 1) / 2) % 2 === 0 ? size - 1 : 0;
+    for (let y = 0; y < size; y++) {
+      if (y !== gap) walls.push({ x: x, y: y });
+    }
+  }
+  return walls;
+}
+
+// Builds a maze board with the serpentine walls drawn as hazards, like
+// arcade_maze does
+function maze(size) {
+  const builder = new GameStateBuilder(size, size).map("arcade_maze");
+  for (let wall of serpentine(size)) builder.hazard(wall.x, wall.y);
+  return builder;
+}
+
+before(() => {
+  mock.method(console, "log", () => {});
+});
+
+test("path lengths across a 25x25 board go past what fits in a byte", () => {
+  const gameState = new GameStateBuilder(25, 25).snake("me", [[0, 0]]).build();
+  const distances = distanceMap(gameState, { x: 0, y: 0 }, serpentine(25));
+  // Along 12 columns of 24 cells, 2 steps through each of the 12 gaps
+  assert.equal(distances.get("24,0"), 12 * 24 + 12 * 2);
+});
+
+test("path lengths across a 50x50 board go past what fits in a byte", () => {
+  const gameState = new GameStateBuilder(50, 50).snake("me", [[0, 0]]).build();
+  const distances = distanceMap(gameState, { x: 0, y: 0 }, serpentine(50));
+  // Along 24 columns of 49 cells, 2 steps through each of the 24 gaps and one
+  // more onto the last column
+  assert.equal(distances.get("49,0"), 24 * 49 + 24 * 2 + 1);
+});
+
+test("on a 25x25 maze the snake goes the long way round a wall to food", () => {
+  // The food is two cells away through the wall, 26 moves away going up
+  // through the gap. Going down leads to just as much room, but away from it.
+  const gameState = maze(25).snake("me", [[12, 12], [12, 12], [12, 12]], 30).food(14, 12).build();
+  assert.equal(move(gameState).move, "up");
+});
+
+test("on a 50x50 maze the snake goes the long way round a wall to food", () => {
+  const gameState = maze(50).snake("me", [[24, 25], [24, 25], [24, 25]], 55).food(26, 25).build();
+  assert.equal(move(gameState).move, "up");
+});
+
+test("with nothing to go for on a 50x50 board the snake heads for the center", () => {
+  const gameState = new GameStateBuilder(50, 50).snake("me", [[3, 3], [3, 2], [3, 1]]).build();
+  assert.ok(["up", "right"].includes(move(gameState).move));
+});
@@ -0,0 +1,107 @@
+import assert from "node:assert/strict";
+import { before, mock, test } from "node:test";
+
+import { directions, step, wrap } from "../board.js";
+import { move } from "../logic.js";
+import { GameStateBuilder } from "./builder.js";
+
+// Small seeded generator, so a failing position can be rebuilt from its seed
+function generator(seed) {
+  return () => {
+    seed = (seed + 0x6d2b79f5) | 0;
+    let t = Math.imul(seed ^ (seed >>> 15), 1 | seed);
+    t = (t + Math.imul(t ^ (t >>> 7), 61 | t)) ^ t;
+    return ((t ^ (t >>> 14)) >>> 0) / 4294967296;
+  };
+}
+
+// A board with one to four snakes laid out as random walks, some still
+// stacked up like on turn 0, plus random food and hazards
+function randomPosition(seed) {
+  const random = generator(seed);
+  const pick = (list) => list[Math.floor(random() * list.length)];
+  const size = pick([7, 11, 19]);
+  const ruleset = pick(["standard", "wrapped", "royale"]);
+  const builder = new GameStateBuilder(size, size).ruleset(ruleset).turn(Math.floor(random() * 200));
+  const gameState = builder.build();
+
+  const taken = new Set();
+  const free = (cell) => !taken.has(`${cell.x},${cell.y}`);
+  const randomCell = () => ({ x: Math.floor(random() * size), y: Math.floor(random() * size) });
+
+  const snakes = 1 + Math.floor(random() * 4);
+  for (let i = 0; i < snakes; i++) {
+    let cell = randomCell();
+    if (!free(cell)) continue;
+    const body = [cell];
+    taken.add(`${cell.x},${cell.y}`);
+    if (random() < 0.1) {
+      body.push(cell, cell);
+    } else {
+      const length = 1 + Math.floor(random() * 12);
+      while (body.length < length) {
+        const options = Object.keys(directions).map((d) => wrap(gameState, step(cell, d)))
+          .filter((n) => n.x >= 0 && n.y >= 0 && n.x < size && n.y < size && free(n));
+        if (options.length === 0) break;
+        cell = pick(options);
+        body.push(cell);
+        taken.add(`${cell.x},${cell.y}`);
+      }
+    }
+    builder.snake(i === 0 ? "me" : `snake-${i}`, body.map((c) => [c.x, c.y]), 1 + Math.floor(random() * 100));
+  }
+
+  for (let i = Math.floor(random() * 6); i > 0; i--) {
+    const cell = randomCell();
+    if (free(cell)) builder.food(cell.x, cell.y);
+  }
+  if (ruleset === "royale") {
+    for (let i = Math.floor(random() * size * 2); i > 0; i--) {
+      const cell = randomCell();
+      builder.hazard(cell.x, cell.y);
+    }
+  }
+  return builder.build();
+}
+
+// Where a move takes our head, and whether that's off the board or onto a
+// body segment that will still be there (tails move off, unless stacked)
+function outcome(gameState, direction) {
+  const pos = wrap(gameState, step(gameState.you.head, direction));
+  const { width, height } = gameState.board;
+  if (pos.x < 0 || pos.y < 0 || pos.x >= width || pos.y >= height) return "out of bounds";
+
+  const hits = gameState.board.snakes.some((snake) => snake.body.some((part, i) => {
+    if (part.x !== pos.x || part.y !== pos.y) return false;
+    const last = snake.body.length - 1;
+    const stacked = last > 0 && snake.body[last - 1].x === part.x && snake.body[last - 1].y === part.y;
+    return i < last || stacked;
+  }));
+  if (hits) return "collision";
+
+  const empty = gameState.board.snakes.every((snake) => snake.body.every((part) => part.x !== pos.x || part.y !== pos.y));
+  return empty ? "empty" : "tail";
+}
+
+before(() => {
+  mock.method(console, "log", () => {});
+});
+
+test("move always answers in time with a legal move, and never walks into a body or off the board when it has a free cell", () => {
+  for (let seed = 1; seed <= 1000; seed++) {
+    const gameState = randomPosition(seed);
+    const started = performance.now();
+    const response = move(gameState);
+    const elapsed = performance.now() - started;
+
+    assert.ok(Object.keys(directions).includes(response.move), `seed ${seed}: answered ${response.move}`);
+    // Half the game's timeout, the other half is for getting the answer back
+    assert.ok(elapsed < gameState.game.timeout / 2, `seed ${seed}: took ${elapsed}ms`);
+
+    const hasEmptyCell = Object.keys(directions).some((d) => outcome(gameState, d) === "empty");
+    if (hasEmptyCell) {
+      const result = outcome(gameState, response.move);
+      assert.ok(result === "empty" || result === "tail", `seed ${seed}: ${response.move} is ${result}`);
+    }
+  }
+});
@@ -0,0 +1,103 @@
+import assert from "node:assert/strict";
+import { mkdtemp, writeFile } from "node:fs/promises";
+import { tmpdir } from "node:os";
+import { join } from "node:path";
+import { mock, test } from "node:test";
+
+import { activeGames, checkpointGames, forgetGame, gameMemory, recordTurn, restoreGames } from "../memory.js";
+import { GameStateBuilder } from "./builder.js";
+
+test("a game we stop hearing about is forgotten", () => {
+  mock.timers.enable({ apis: ["Date"], now: 0 });
+  try {
+    const gameState = new GameStateBuilder(11, 11).snake("me", [[5, 5], [5, 4], [5, 3]]).build();
+    gameMemory(gameState);
+    assert.equal(activeGames(), 1);
+
+    mock.timers.tick(5 * 60 * 1000);
+    assert.equal(activeGames(), 1);
+
+    mock.timers.tick(6 * 60 * 1000);
+    assert.equal(activeGames(), 0);
+  } finally {
+    mock.timers.reset();
+  }
+});
+
+test("what we remember survives a checkpoint and restore", async (t) => {
+  t.mock.method(console, "log", () => {});
+  const file = join(await mkdtemp(join(tmpdir(), "snake-")), "games.json");
+  const gameState = new GameStateBuilder(11, 11)
+    .snake("me", [[5, 5], [5, 4], [5, 3]])
+    .snake("other", [[1, 1], [1, 2], [1, 3]])
+    .hazard(0, 0)
+    .build();
+  recordTurn(gameState);
+  recordTurn({ ...gameState, turn: 1 });
+  await checkpointGames(file);
+
+  forgetGame(gameState);
+  restoreGames(file);
+  const memory = gameMemory(gameState);
+  assert.equal(memory.previous.turn, 1);
+  assert.ok(memory.previous.snakes.has("other"));
+  assert.ok(memory.previous.hazards.has("0,0"));
+  assert.deepEqual(memory.foodChasing, new Map());
+
+  // Carries on from the restored turn as if nothing happened
+  assert.doesNotThrow(() => recordTurn({ ...gameState, turn: 2 }));
+});
+
+test("a missing checkpoint file starts us off with nothing", () => {
+  assert.doesNotThrow(() => restoreGames(join(tmpdir(), "no-such-snake-checkpoint.json")));
+});
+
+test("an unversioned checkpoint from an older build is read and filled in with what it didn't save", async () => {
+  const file = join(await mkdtemp(join(tmpdir(), "snake-")), "games.json");
+  await writeFile(file, JSON.stringify([["old-game", { snakesAtStart: 4, peakLength: 7, foodEaten: 2, eliminated: 1 }]]));
+  restoreGames(file);
+
+  const gameState = new GameStateBuilder(11, 11).snake("me", [[5, 5], [5, 4], [5, 3]]).build();
+  gameState.game.id = "old-game";
+  const memory = gameMemory(gameState);
+  assert.equal(memory.snakesAtStart, 4);
+  assert.deepEqual(memory.hazardSpread, []);
+  assert.deepEqual(memory.moveTimes, []);
+  assert.deepEqual(memory.foodChasing, new Map());
+  forgetGame(gameState);
+});
+
+test("a checkpoint that isn't a list of games is ignored", async () => {
+  const file = join(await mkdtemp(join(tmpdir(), "snake-")), "games.json");
+  await writeFile(file, "{}");
+  assert.doesNotThrow(() => restoreGames(file));
+});
+
+test("a checkpoint from a version we don't know is ignored", async () => {
+  const file = join(await mkdtemp(join(tmpdir(), "snake-")), "games.json");
+  await writeFile(file, JSON.stringify({ version: 99, games: [["future-game", {}]] }));
+  const before = activeGames();
+  restoreGames(file);
+  assert.equal(activeGames(), before);
+});
+
+test("a turn that doesn't follow on from the last one resets what we've worked out", (t) => {
+  t.mock.method(console, "log", () => {});
+  const builder = (turn, head) => new GameStateBuilder(11, 11)
+    .turn(turn)
+    .snake("me", [head, [head[0], head[1] - 1], [head[0], head[1] - 2]])
+    .snake("other", [[1, 1], [1, 2], [1, 3]])
+    .build();
+  forgetGame(builder(0, [5, 5]));
+  recordTurn(builder(0, [5, 5]));
+  recordTurn(builder(1, [5, 6])).hazardSpread.push({ turn: 1, cells: 3 });
+
+  // Two turns on, we'd have had to move twice
+  const memory = recordTurn(builder(3, [5, 8]));
+  assert.equal(memory.memoryResets, 1);
+  assert.deepEqual(memory.hazardSpread, []);
+  assert.equal(memory.previous.turn, 3);
+
+  // Carries on normally from there
+  assert.equal(recordTurn(builder(4, [5, 9])).memoryResets, 1);
+});
@@ -0,0 +1,30 @@
+import assert from "node:assert/strict";
+import { before, mock, test } from "node:test";
+
+import { move } from "../logic.js";
+import { GameStateBuilder } from "./builder.js";
+
+before(() => {
+  mock.method(console, "log", () => {});
+});
+
+test("a possible head-to-head beats walking into a pocket with no room", () => {
+  // Up is walled in by the long snake, down is open but next to the head of
+  // a snake our length
+  const gameState = new GameStateBuilder(7, 7)
+    .snake("me", [[3, 3], [2, 3], [1, 3], [0, 3], [0, 2]])
+    .snake("wall", [[4, 3], [4, 4], [4, 5], [3, 5], [2, 5], [2, 4], [1, 4], [0, 4]])
+    .snake("rival", [[4, 2], [5, 2], [6, 2], [6, 1], [6, 0]])
+    .build();
+  assert.equal(move(gameState).move, "down");
+});
+
+test("the snake can follow right behind its own tail", () => {
+  // Boxed in in the corner, the only way on is the cell our tail is about to
+  // leave
+  const gameState = new GameStateBuilder(11, 11)
+    .snake("me", [[0, 1], [1, 1], [1, 0], [0, 0]])
+    .snake("other", [[2, 2], [1, 2], [0, 2], [0, 3], [0, 4]])
+    .build();
+  assert.equal(move(gameState).move, "down");
+});
@@ -0,0 +1,31 @@
+import assert from "node:assert/strict";
+import { before, mock, test } from "node:test";
+
+import { move } from "../logic.js";
+import { forgetGame, gameMemory } from "../memory.js";
+import { GameStateBuilder } from "./builder.js";
+
+before(() => {
+  mock.method(console, "log", () => {});
+});
+
+test("a little longer than a snake close by, we move diagonal to its head and remember it", () => {
+  const gameState = new GameStateBuilder(11, 11)
+    .snake("me", [[5, 5], [5, 4], [5, 3], [5, 2], [5, 1]], 50)
+    .snake("target", [[6, 7], [7, 7], [8, 7], [9, 7]])
+    .food(2, 5)
+    .build();
+  forgetGame(gameState);
+  assert.equal(move(gameState).move, "up");
+  assert.equal(gameMemory(gameState).shadowing, "target");
+});
+
+test("hungry, we go for food instead of shadowing", () => {
+  const gameState = new GameStateBuilder(11, 11)
+    .snake("me", [[5, 5], [5, 4], [5, 3], [5, 2], [5, 1]], 20)
+    .snake("target", [[6, 7], [7, 7], [8, 7], [9, 7]])
+    .food(2, 5)
+    .build();
+  forgetGame(gameState);
+  assert.equal(move(gameState).move, "left");
+});
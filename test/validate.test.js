@@ -0,0 +1,38 @@
+import assert from "node:assert/strict";
+import { test } from "node:test";
+
+import { validateGameState } from "../validate.js";
+import { GameStateBuilder } from "./builder.js";
+
+function gameState() {
+  return new GameStateBuilder(11, 11)
+    .snake("me", [[5, 5], [5, 4], [5, 3]])
+    .snake("other", [[1, 1], [1, 2], [1, 3]])
+    .build();
+}
+
+test("a well formed request passes", () => {
+  assert.equal(validateGameState(gameState()), undefined);
+});
+
+test("an opponent without a body is turned away", () => {
+  const request = gameState();
+  delete request.board.snakes[1].body;
+  assert.equal(validateGameState(request), "board.snakes[1].body must not be empty");
+});
+
+test("an opponent without a head or length is turned away", () => {
+  const headless = gameState();
+  delete headless.board.snakes[1].head;
+  assert.equal(validateGameState(headless), "board.snakes[1].head must be an {x, y} point");
+
+  const lengthless = gameState();
+  delete lengthless.board.snakes[1].length;
+  assert.equal(validateGameState(lengthless), "board.snakes[1].length must be positive");
+});
+
+test("you without a head is turned away", () => {
+  const request = gameState();
+  request.you = { ...request.you, head: undefined };
+  assert.equal(validateGameState(request), "you.head must be an {x, y} point");
+});
@@ -0,0 +1,30 @@
+import { hazardsAreWalls } from './occupancy.js';
+import { predictedLength } from './headDanger.js';
+
+// For every cell covered by a snake, the number of turns until it's free to
+// move onto. Segment i of a body (counting from the head) moves off its cell
+// after length - i turns, one more if the snake is about to eat and grow.
+// Cells that never free up, like maze walls, are Infinity. Built once per game
+// state like occupancy, so callers get a shared map they mustn't change.
+const cache = new WeakMap();
+
+export function turnsUntilFree(gameState) {
+  if (cache.has(gameState)) return cache.get(gameState);
+
+  const cells = new Map();
+  gameState.board.snakes.forEach((snake) => {
+    const growth = predictedLength(gameState, snake) - snake.length;
+    snake.body.forEach((part, i) => {
+      const key = `${part.x},${part.y}`;
+      const turns = snake.body.length - i + growth;
+      cells.set(key, Math.max(cells.get(key) ?? 0, turns));
+    });
+  });
+  if (hazardsAreWalls(gameState)) {
+    for (let h of gameState.board.hazards) {
+      cells.set(`${h.x},${h.y}`, Infinity);
+    }
+  }
+  cache.set(gameState, cells);
+  return cells;
+}
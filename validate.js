@@ -0,0 +1,39 @@
+// Checks a request has what the logic relies on and returns a description of
+// the first problem found, or undefined if it looks fine. Dead snakes are off
+// the board, so `you` only has to be on it while we're still playing.
+export function validateGameState(gameState, alive = true) {
+  if (typeof gameState !== "object" || gameState === null) return "body must be a JSON object";
+
+  const board = gameState.board;
+  if (typeof board !== "object" || board === null) return "board is missing";
+  if (!(board.width > 0) || !(board.height > 0)) return "board width and height must be positive";
+  if (board.snakes !== undefined && !Array.isArray(board.snakes)) return "board.snakes must be a list";
+  for (let [i, snake] of (board.snakes ?? []).entries()) {
+    const problem = snakeProblem(snake, `board.snakes[${i}]`);
+    if (problem) return problem;
+  }
+
+  const you = gameState.you;
+  if (typeof you !== "object" || you === null) return "you is missing";
+  const problem = snakeProblem(you, "you");
+  if (problem) return problem;
+  if (alive && !(board.snakes ?? []).some((snake) => snake.id === you.id)) return "you must be one of board.snakes";
+
+  if (gameState.turn !== undefined && !(gameState.turn >= 0)) return "turn must not be negative";
+
+  return undefined;
+}
+
+// Checks a snake has the body, head and length the logic reads
+function snakeProblem(snake, name) {
+  if (typeof snake !== "object" || snake === null) return `${name} must be an object`;
+  if (!Array.isArray(snake.body) || snake.body.length === 0) return `${name}.body must not be empty`;
+  if (!snake.body.every(isPoint)) return `${name}.body must be a list of {x, y} points`;
+  if (!isPoint(snake.head)) return `${name}.head must be an {x, y} point`;
+  if (!(snake.length > 0)) return `${name}.length must be positive`;
+  return undefined;
+}
+
+function isPoint(point) {
+  return typeof point === "object" && point !== null && Number.isInteger(point.x) && Number.isInteger(point.y);
+}